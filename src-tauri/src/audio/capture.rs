@@ -1,16 +1,153 @@
-use std::sync::{Arc, Mutex, atomic::{AtomicBool, Ordering}};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, atomic::{AtomicBool, AtomicUsize, Ordering}};
 use anyhow::{Result, Context};
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use serde::Serialize;
 
 use crate::engine::AudioBuffer;
 
 const TARGET_SAMPLE_RATE: u32 = 16000;
 
+#[derive(Debug, Clone, Serialize)]
+pub struct InputDeviceInfo {
+    pub name: String,
+    pub is_default: bool,
+    pub configs: Vec<InputConfigInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InputConfigInfo {
+    pub channels: u16,
+    pub min_sample_rate: u32,
+    pub max_sample_rate: u32,
+    pub sample_format: String,
+}
+
+pub fn list_input_devices() -> Result<Vec<InputDeviceInfo>> {
+    let host = cpal::default_host();
+    let default_name = host.default_input_device().and_then(|d| d.name().ok());
+    let devices = host.input_devices().context("Failed to enumerate input devices")?;
+
+    Ok(devices
+        .filter_map(|device| {
+            let name = device.name().ok()?;
+            let configs = device.supported_input_configs()
+                .map(|configs| configs
+                    .map(|c| InputConfigInfo {
+                        channels: c.channels(),
+                        min_sample_rate: c.min_sample_rate().0,
+                        max_sample_rate: c.max_sample_rate().0,
+                        sample_format: c.sample_format().to_string(),
+                    })
+                    .collect())
+                .unwrap_or_default();
+            Some(InputDeviceInfo {
+                is_default: default_name.as_deref() == Some(name.as_str()),
+                name,
+                configs,
+            })
+        })
+        .collect())
+}
+
+/// Resolves the fallback chain: the first connected device of `preferred`, then the
+/// system default, then any other input device. Devices named in `exclude` are skipped.
+fn select_input_device(preferred: &[String], exclude: Option<&str>) -> Result<(cpal::Device, String)> {
+    let host = cpal::default_host();
+    let mut available: Vec<(cpal::Device, String)> = host.input_devices()
+        .context("Failed to enumerate input devices")?
+        .filter_map(|d| d.name().ok().map(|name| (d, name)))
+        .filter(|(_, name)| Some(name.as_str()) != exclude)
+        .collect();
+
+    for wanted in preferred {
+        if let Some(i) = available.iter().position(|(_, name)| name == wanted) {
+            return Ok(available.swap_remove(i));
+        }
+    }
+    if let Some(device) = host.default_input_device() {
+        let name = device.name().unwrap_or_else(|_| "Default input".to_string());
+        if Some(name.as_str()) != exclude {
+            return Ok((device, name));
+        }
+    }
+    available.into_iter().next().context("No input device available")
+}
+
+/// The device's default config, or one at `sample_rate` when it supports that rate, so
+/// a recording can continue on another device without changing rate.
+fn input_stream_config(device: &cpal::Device, sample_rate: Option<u32>) -> Result<cpal::StreamConfig> {
+    if let Some(rate) = sample_rate {
+        let matching = device.supported_input_configs().ok().and_then(|mut configs| {
+            configs.find(|c| {
+                c.sample_format() == cpal::SampleFormat::F32
+                    && c.min_sample_rate().0 <= rate
+                    && rate <= c.max_sample_rate().0
+            })
+        });
+        if let Some(range) = matching {
+            return Ok(range.with_sample_rate(cpal::SampleRate(rate)).into());
+        }
+    }
+    let config = device.default_input_config()
+        .context("Failed to get default input config")?;
+    Ok(config.into())
+}
+
+/// Streaming linear interpolation, for a replacement device whose rate differs from the
+/// recording's.
+struct LinearResampler {
+    step: f64,
+    pos: f64,
+    last: f32,
+}
+
+impl LinearResampler {
+    fn new(from_rate: u32, to_rate: u32) -> Self {
+        Self { step: from_rate as f64 / to_rate as f64, pos: 1.0, last: 0.0 }
+    }
+
+    fn process(&mut self, input: &[f32], out: &mut Vec<f32>) {
+        // Positions index `[last, input...]`, so interpolation can span callbacks
+        let len = input.len() as f64;
+        while self.pos < len {
+            let i = self.pos as usize;
+            let a = if i == 0 { self.last } else { input[i - 1] };
+            let b = input[i];
+            out.push(a + (b - a) * (self.pos - i as f64) as f32);
+            self.pos += self.step;
+        }
+        self.pos -= len;
+        if let Some(&last) = input.last() {
+            self.last = last;
+        }
+    }
+}
+
 pub struct AudioCapture {
     samples: Arc<Mutex<Vec<f32>>>,
     is_recording: Arc<AtomicBool>,
+    /// Device-rate samples handed out by `StreamingDrain::drain` since `start()`.
+    drained: Arc<AtomicUsize>,
     stream: Option<cpal::Stream>,
+    /// Set when recording from a warm mic's stream instead of `stream`; keeps it open
+    /// until `stop()` even if warm mic is turned off meanwhile.
+    warm: Option<Arc<WarmMic>>,
     device_sample_rate: u32,
+    /// Fallback chain used by `start()` and `failover()`.
+    preferred_devices: Vec<String>,
+    device_name: Option<String>,
+    /// Set by the stream's error callback when the device disappears.
+    device_lost: Arc<AtomicBool>,
+    /// Samples delivered by the device, recording or not; stops growing if it stalls.
+    frames_received: Arc<AtomicUsize>,
+}
+
+/// A built, not yet started input stream.
+struct CaptureStream {
+    stream: cpal::Stream,
+    recording_rate: u32,
+    device_lost: Arc<AtomicBool>,
 }
 
 // Safety: cpal::Stream on macOS wraps a CoreAudio AudioUnit which is thread-safe.
@@ -24,55 +161,157 @@ impl AudioCapture {
         Ok(Self {
             samples: Arc::new(Mutex::new(Vec::new())),
             is_recording: Arc::new(AtomicBool::new(false)),
+            drained: Arc::new(AtomicUsize::new(0)),
             stream: None,
+            warm: None,
             device_sample_rate: TARGET_SAMPLE_RATE,
+            preferred_devices: Vec::new(),
+            device_name: None,
+            device_lost: Arc::new(AtomicBool::new(false)),
+            frames_received: Arc::new(AtomicUsize::new(0)),
         })
     }
 
-    pub fn start(&mut self) -> Result<()> {
-        let host = cpal::default_host();
-        let device = host.default_input_device()
-            .context("No input device available")?;
-
-        let config = device.default_input_config()
-            .context("Failed to get default input config")?;
+    /// Like `start()`, but records from an already open warm mic stream. The recording
+    /// begins with the warm mic's buffered pre-roll. `preferred_devices` is the chain
+    /// used by `failover()`.
+    pub fn start_warm(&mut self, warm: Arc<WarmMic>, preferred_devices: &[String]) {
+        self.preferred_devices = preferred_devices.to_vec();
+        self.device_sample_rate = warm.device_sample_rate;
+        self.device_name = Some(warm.device_name.clone());
+        self.samples.lock().unwrap().clear();
+        self.drained.store(0, Ordering::SeqCst);
+        self.is_recording.store(true, Ordering::SeqCst);
+
+        let pre_roll = warm.attach(WarmMicSink {
+            samples: Arc::clone(&self.samples),
+            is_recording: Arc::clone(&self.is_recording),
+            frames_received: Arc::clone(&self.frames_received),
+        });
+        self.warm = Some(warm);
+
+        tracing::info!(
+            "Audio capture started from warm mic ({} pre-roll samples at {}Hz)",
+            pre_roll, self.device_sample_rate
+        );
+    }
 
-        self.device_sample_rate = config.sample_rate().0;
+    /// Records from the first available device of `preferred_devices` (by name), falling
+    /// back to the system default.
+    pub fn start(&mut self, preferred_devices: &[String]) -> Result<()> {
+        self.preferred_devices = preferred_devices.to_vec();
+        self.samples.lock().unwrap().clear();
+        self.drained.store(0, Ordering::SeqCst);
+        self.is_recording.store(true, Ordering::SeqCst);
+
+        let (device, name) = select_input_device(preferred_devices, None)?;
+        let stream = self.build_stream(&device, &name, None)?;
+        self.use_stream(stream, name)?;
+
+        tracing::info!(
+            "Audio capture started on {} (device sample rate: {}Hz)",
+            self.device_name.as_deref().unwrap_or_default(), self.device_sample_rate
+        );
+        Ok(())
+    }
 
-        let samples = Arc::clone(&self.samples);
-        let is_recording = Arc::clone(&self.is_recording);
+    /// Moves the recording to the next device of the fallback chain, after the current
+    /// one disconnected or stopped delivering audio. Audio recorded so far is kept and
+    /// the recording's sample rate doesn't change. Returns the new device's name.
+    ///
+    /// The current stream is only replaced once the new one is open: when there is no
+    /// other device, this fails and the recording stays on the current one.
+    pub fn failover(&mut self) -> Result<String> {
+        let previous = self.device_name.clone();
+        let (device, name) = select_input_device(&self.preferred_devices, previous.as_deref())?;
+        let stream = self.build_stream(&device, &name, Some(self.device_sample_rate))?;
+
+        // Stop the old source before the new one starts, so no audio is written twice
+        self.stream = None;
+        if let Some(warm) = self.warm.take() {
+            warm.detach();
+        }
+        self.use_stream(stream, name.clone())?;
 
-        samples.lock().unwrap().clear();
-        is_recording.store(true, Ordering::SeqCst);
+        tracing::info!(
+            "Audio capture moved from {} to {}",
+            previous.as_deref().unwrap_or("unknown device"), name
+        );
+        Ok(name)
+    }
 
-        let stream_config: cpal::StreamConfig = config.into();
+    /// Builds a stream from `device` that appends to the recording, without starting it.
+    /// With `recording_rate`, audio is converted to that rate if the device can't run at it.
+    fn build_stream(&self, device: &cpal::Device, name: &str, recording_rate: Option<u32>) -> Result<CaptureStream> {
+        let stream_config = input_stream_config(device, recording_rate)?;
+        let stream_rate = stream_config.sample_rate.0;
+        let recording_rate = recording_rate.unwrap_or(stream_rate);
         let channels = stream_config.channels as usize;
 
+        let samples = Arc::clone(&self.samples);
+        let is_recording = Arc::clone(&self.is_recording);
+        let frames_received = Arc::clone(&self.frames_received);
+        // Per stream, so a late error from a replaced device doesn't flag the new one
+        let device_lost = Arc::new(AtomicBool::new(false));
+        let callback_lost = Arc::clone(&device_lost);
+        let mut resampler = (stream_rate != recording_rate)
+            .then(|| LinearResampler::new(stream_rate, recording_rate));
+        let error_name = name.to_string();
+
         let stream = device.build_input_stream(
             &stream_config,
             move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                frames_received.fetch_add(data.len() / channels, Ordering::SeqCst);
                 if is_recording.load(Ordering::SeqCst) {
                     let mono: Vec<f32> = data.iter().step_by(channels).copied().collect();
-                    samples.lock().unwrap().extend_from_slice(&mono);
+                    let mut samples = samples.lock().unwrap();
+                    match resampler.as_mut() {
+                        Some(resampler) => resampler.process(&mono, &mut samples),
+                        None => samples.extend_from_slice(&mono),
+                    }
                 }
             },
-            |err| {
-                tracing::error!("Audio capture error: {}", err);
+            move |err| {
+                tracing::error!("Audio capture error on {}: {}", error_name, err);
+                if matches!(err, cpal::StreamError::DeviceNotAvailable) {
+                    callback_lost.store(true, Ordering::SeqCst);
+                }
             },
             None,
         ).context("Failed to build input stream")?;
 
-        stream.play().context("Failed to start audio stream")?;
-        self.stream = Some(stream);
+        Ok(CaptureStream { stream, recording_rate, device_lost })
+    }
 
-        tracing::info!("Audio capture started (device sample rate: {}Hz)", self.device_sample_rate);
+    fn use_stream(&mut self, stream: CaptureStream, name: String) -> Result<()> {
+        stream.stream.play().context("Failed to start audio stream")?;
+        self.stream = Some(stream.stream);
+        self.device_sample_rate = stream.recording_rate;
+        self.device_lost = stream.device_lost;
+        self.device_name = Some(name);
         Ok(())
     }
 
+    pub fn device_name(&self) -> Option<&str> {
+        self.device_name.as_deref()
+    }
+
+    /// The input device reported that it is gone.
+    pub fn device_lost(&self) -> bool {
+        self.device_lost.load(Ordering::SeqCst)
+            || self.warm.as_ref().is_some_and(|warm| warm.is_lost())
+    }
+
+    /// Frames delivered by the input device so far.
+    pub fn frames_received(&self) -> usize {
+        self.frames_received.load(Ordering::SeqCst)
+    }
+
     pub fn stop(&mut self) -> Result<AudioBuffer> {
         self.is_recording.store(false, Ordering::SeqCst);
 
         self.stream = None;
+        self.warm = None;
 
         let raw_samples = {
             let mut guard = self.samples.lock().unwrap();
@@ -99,6 +338,16 @@ impl AudioCapture {
         })
     }
 
+    /// Position of the not-yet-drained audio in the recording, in device-rate samples.
+    /// After `stop()` this is where the returned tail starts.
+    pub fn drained_samples(&self) -> usize {
+        self.drained.load(Ordering::SeqCst)
+    }
+
+    pub fn device_sample_rate(&self) -> u32 {
+        self.device_sample_rate
+    }
+
     pub fn current_level(&self) -> f32 {
         let guard = self.samples.lock().unwrap();
         if guard.is_empty() {
@@ -126,6 +375,7 @@ impl AudioCapture {
         StreamingDrain {
             samples: Arc::clone(&self.samples),
             is_recording: Arc::clone(&self.is_recording),
+            drained: Arc::clone(&self.drained),
             device_sample_rate: self.device_sample_rate,
         }
     }
@@ -156,13 +406,19 @@ impl LevelMonitor {
 pub struct StreamingDrain {
     samples: Arc<Mutex<Vec<f32>>>,
     is_recording: Arc<AtomicBool>,
+    drained: Arc<AtomicUsize>,
     device_sample_rate: u32,
 }
 
 impl StreamingDrain {
-    pub fn drain(&self) -> Vec<f32> {
+    /// Takes all buffered samples. Returns the offset of the first one in the recording
+    /// (in device-rate samples) along with the samples.
+    pub fn drain(&self) -> (usize, Vec<f32>) {
         let mut guard = self.samples.lock().unwrap();
-        std::mem::take(&mut *guard)
+        let samples = std::mem::take(&mut *guard);
+        // Updated under the samples lock so stop() always sees a consistent offset
+        let offset = self.drained.fetch_add(samples.len(), Ordering::SeqCst);
+        (offset, samples)
     }
 
     pub fn available_samples(&self) -> usize {
@@ -177,3 +433,119 @@ impl StreamingDrain {
         self.device_sample_rate
     }
 }
+
+struct WarmMicSink {
+    samples: Arc<Mutex<Vec<f32>>>,
+    is_recording: Arc<AtomicBool>,
+    frames_received: Arc<AtomicUsize>,
+}
+
+/// Keeps the preferred input device open between recordings so that start-up latency
+/// doesn't clip the first word. The last `pre_roll_ms` of audio are kept in a ring
+/// buffer, in memory only, and handed to the next recording; older audio is discarded
+/// as it arrives.
+pub struct WarmMic {
+    _stream: cpal::Stream,
+    sink: Arc<Mutex<Option<WarmMicSink>>>,
+    ring: Arc<Mutex<VecDeque<f32>>>,
+    device_sample_rate: u32,
+    device_name: String,
+    lost: Arc<AtomicBool>,
+}
+
+// Safety: see AudioCapture. The stream is only dropped, never accessed concurrently.
+unsafe impl Send for WarmMic {}
+unsafe impl Sync for WarmMic {}
+
+impl WarmMic {
+    pub fn open(pre_roll_ms: u64, preferred_devices: &[String]) -> Result<Self> {
+        let (device, device_name) = select_input_device(preferred_devices, None)?;
+
+        let config = device.default_input_config()
+            .context("Failed to get default input config")?;
+
+        let device_sample_rate = config.sample_rate().0;
+        let capacity = (pre_roll_ms * device_sample_rate as u64 / 1000) as usize;
+
+        let sink: Arc<Mutex<Option<WarmMicSink>>> = Arc::new(Mutex::new(None));
+        let ring = Arc::new(Mutex::new(VecDeque::with_capacity(capacity)));
+
+        let stream_config: cpal::StreamConfig = config.into();
+        let channels = stream_config.channels as usize;
+
+        let lost = Arc::new(AtomicBool::new(false));
+        let callback_sink = Arc::clone(&sink);
+        let callback_ring = Arc::clone(&ring);
+        let callback_lost = Arc::clone(&lost);
+        let stream = device.build_input_stream(
+            &stream_config,
+            move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                let mono = data.iter().step_by(channels).copied();
+                let mut sink = callback_sink.lock().unwrap();
+                if let Some(active) = sink.as_ref() {
+                    if active.is_recording.load(Ordering::SeqCst) {
+                        active.frames_received.fetch_add(data.len() / channels, Ordering::SeqCst);
+                        active.samples.lock().unwrap().extend(mono);
+                        return;
+                    }
+                    *sink = None;
+                }
+                let mut ring = callback_ring.lock().unwrap();
+                ring.extend(mono);
+                let excess = ring.len().saturating_sub(capacity);
+                ring.drain(..excess);
+            },
+            move |err| {
+                tracing::error!("Warm mic error: {}", err);
+                if matches!(err, cpal::StreamError::DeviceNotAvailable) {
+                    callback_lost.store(true, Ordering::SeqCst);
+                }
+            },
+            None,
+        ).context("Failed to build input stream")?;
+
+        stream.play().context("Failed to start audio stream")?;
+
+        tracing::info!(
+            "Warm mic opened on {} ({}ms pre-roll at {}Hz)",
+            device_name, pre_roll_ms, device_sample_rate
+        );
+        Ok(Self {
+            _stream: stream,
+            sink,
+            ring,
+            device_sample_rate,
+            device_name,
+            lost,
+        })
+    }
+
+    pub fn is_lost(&self) -> bool {
+        self.lost.load(Ordering::SeqCst)
+    }
+
+    /// Moves the pre-roll into the sink's samples and routes the stream there until its
+    /// `is_recording` is cleared. Returns the number of pre-roll samples.
+    fn attach(&self, target: WarmMicSink) -> usize {
+        // Holding the sink lock keeps the callback out, so nothing falls between the
+        // pre-roll and the first live samples
+        let mut sink = self.sink.lock().unwrap();
+        let pre_roll: Vec<f32> = self.ring.lock().unwrap().drain(..).collect();
+        let len = pre_roll.len();
+        target.samples.lock().unwrap().extend(pre_roll);
+        *sink = Some(target);
+        len
+    }
+
+    /// Stops feeding the attached recording, which has moved to another device.
+    fn detach(&self) {
+        *self.sink.lock().unwrap() = None;
+    }
+}
+
+impl Drop for WarmMic {
+    fn drop(&mut self) {
+        self.ring.lock().unwrap().clear();
+        tracing::info!("Warm mic closed");
+    }
+}
@@ -1,5 +1,7 @@
 pub mod capture;
+pub mod decode;
 pub mod playback;
 pub mod processing;
 
 pub use capture::AudioCapture;
+pub use playback::AudioPlayback;
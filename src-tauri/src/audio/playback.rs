@@ -1,22 +1,254 @@
-use anyhow::Result;
+use std::sync::{Arc, Mutex, atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering}};
+use anyhow::{Result, Context};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use tauri::{AppHandle, Emitter};
+
 use crate::engine::AudioBuffer;
 
-pub struct AudioPlayback;
+const PROGRESS_INTERVAL_MS: u64 = 100;
+
+struct PlaybackShared {
+    samples: Mutex<Vec<f32>>,
+    position: AtomicUsize,
+    sample_rate: AtomicU32,
+    active: AtomicBool,
+    paused: AtomicBool,
+    generation: AtomicU64,
+}
+
+impl PlaybackShared {
+    fn duration_ms(&self) -> u64 {
+        let len = self.samples.lock().unwrap().len();
+        samples_to_ms(len, self.sample_rate.load(Ordering::SeqCst))
+    }
+
+    fn position_ms(&self) -> u64 {
+        samples_to_ms(self.position.load(Ordering::SeqCst), self.sample_rate.load(Ordering::SeqCst))
+    }
+}
+
+fn samples_to_ms(samples: usize, sample_rate: u32) -> u64 {
+    if sample_rate == 0 {
+        return 0;
+    }
+    samples as u64 * 1000 / sample_rate as u64
+}
+
+// Safety: same reasoning as AudioCapture — the CoreAudio output unit behind cpal::Stream
+// is thread-safe, and the stream is only touched behind a Mutex.
+struct OutputStream(cpal::Stream);
+unsafe impl Send for OutputStream {}
+
+/// Non-blocking playback of an `AudioBuffer` on the default output device.
+/// Emits `playback-status` and `playback-progress` events while playing.
+pub struct AudioPlayback {
+    app_handle: AppHandle,
+    shared: Arc<PlaybackShared>,
+    stream: Arc<Mutex<Option<OutputStream>>>,
+}
 
 impl AudioPlayback {
-    pub fn new() -> Result<Self> {
-        Ok(Self)
+    pub fn new(app_handle: &AppHandle) -> Result<Self> {
+        Ok(Self {
+            app_handle: app_handle.clone(),
+            shared: Arc::new(PlaybackShared {
+                samples: Mutex::new(Vec::new()),
+                position: AtomicUsize::new(0),
+                sample_rate: AtomicU32::new(0),
+                active: AtomicBool::new(false),
+                paused: AtomicBool::new(false),
+                generation: AtomicU64::new(0),
+            }),
+            stream: Arc::new(Mutex::new(None)),
+        })
     }
 
-    pub fn play(&self, _audio: &AudioBuffer) -> Result<()> {
-        todo!("Audio playback - Phase 6: play AudioBuffer via cpal output device")
+    /// Starts playing `audio`, replacing any playback in progress. Returns immediately.
+    pub fn play(&self, audio: &AudioBuffer) -> Result<()> {
+        self.halt();
+
+        let host = cpal::default_host();
+        let device = host.default_output_device()
+            .context("No output device available")?;
+
+        let config = device.default_output_config()
+            .context("Failed to get default output config")?;
+
+        let device_rate = config.sample_rate().0;
+        let stream_config: cpal::StreamConfig = config.into();
+        let channels = stream_config.channels as usize;
+
+        let mono = super::processing::downmix_to_mono(&audio.samples, audio.channels);
+
+        let samples = if audio.sample_rate != device_rate {
+            super::processing::resample(&mono, audio.sample_rate, device_rate)?
+        } else {
+            mono
+        };
+
+        *self.shared.samples.lock().unwrap() = samples;
+        self.shared.sample_rate.store(device_rate, Ordering::SeqCst);
+        self.shared.position.store(0, Ordering::SeqCst);
+        self.shared.paused.store(false, Ordering::SeqCst);
+        self.shared.active.store(true, Ordering::SeqCst);
+        let generation = self.shared.generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+        let shared = Arc::clone(&self.shared);
+        let stream = device.build_output_stream(
+            &stream_config,
+            move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+                let guard = match shared.samples.try_lock() {
+                    Ok(g) if shared.active.load(Ordering::SeqCst)
+                        && !shared.paused.load(Ordering::SeqCst) => g,
+                    _ => {
+                        data.fill(0.0);
+                        return;
+                    }
+                };
+
+                let mut pos = shared.position.load(Ordering::SeqCst);
+                for frame in data.chunks_mut(channels) {
+                    let sample = guard.get(pos).copied().unwrap_or(0.0);
+                    frame.fill(sample);
+                    if pos < guard.len() {
+                        pos += 1;
+                    }
+                }
+                shared.position.store(pos, Ordering::SeqCst);
+
+                if pos >= guard.len() {
+                    shared.active.store(false, Ordering::SeqCst);
+                }
+            },
+            |err| {
+                tracing::error!("Audio playback error: {}", err);
+            },
+            None,
+        )
+        .context("Failed to build output stream")
+        .inspect_err(|_| self.shared.active.store(false, Ordering::SeqCst))?;
+
+        stream.play()
+            .context("Failed to start output stream")
+            .inspect_err(|_| self.shared.active.store(false, Ordering::SeqCst))?;
+        *self.stream.lock().unwrap() = Some(OutputStream(stream));
+
+        let duration_ms = self.shared.duration_ms();
+        tracing::info!("Playback started: {}ms at {}Hz ({} ch)", duration_ms, device_rate, channels);
+        let _ = self.app_handle.emit("playback-status", serde_json::json!({
+            "status": "playing",
+            "duration_ms": duration_ms,
+        }));
+
+        self.spawn_progress_thread(generation);
+        Ok(())
     }
 
     pub fn stop(&self) -> Result<()> {
-        todo!("Audio playback stop - Phase 6")
+        let was_active = self.shared.active.load(Ordering::SeqCst);
+        self.halt();
+        if was_active {
+            tracing::info!("Playback stopped");
+        }
+        let _ = self.app_handle.emit("playback-status", serde_json::json!({"status": "idle"}));
+        Ok(())
+    }
+
+    pub fn pause(&self) -> Result<()> {
+        if !self.shared.active.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        self.shared.paused.store(true, Ordering::SeqCst);
+        let _ = self.app_handle.emit("playback-status", serde_json::json!({
+            "status": "paused",
+            "position_ms": self.shared.position_ms(),
+            "duration_ms": self.shared.duration_ms(),
+        }));
+        Ok(())
+    }
+
+    pub fn resume(&self) -> Result<()> {
+        if !self.shared.active.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        self.shared.paused.store(false, Ordering::SeqCst);
+        let _ = self.app_handle.emit("playback-status", serde_json::json!({
+            "status": "playing",
+            "position_ms": self.shared.position_ms(),
+            "duration_ms": self.shared.duration_ms(),
+        }));
+        Ok(())
     }
 
     pub fn is_playing(&self) -> bool {
-        false
+        self.shared.active.load(Ordering::SeqCst) && !self.shared.paused.load(Ordering::SeqCst)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.shared.active.load(Ordering::SeqCst) && self.shared.paused.load(Ordering::SeqCst)
+    }
+
+    pub fn position_ms(&self) -> u64 {
+        self.shared.position_ms()
+    }
+
+    pub fn duration_ms(&self) -> u64 {
+        self.shared.duration_ms()
+    }
+
+    /// Ends the current playback without emitting events. Bumping the generation
+    /// makes the previous progress thread exit quietly.
+    fn halt(&self) {
+        self.shared.generation.fetch_add(1, Ordering::SeqCst);
+        self.shared.active.store(false, Ordering::SeqCst);
+        self.shared.paused.store(false, Ordering::SeqCst);
+        *self.stream.lock().unwrap() = None;
+    }
+
+    fn spawn_progress_thread(&self, generation: u64) {
+        let shared = Arc::clone(&self.shared);
+        let stream = Arc::clone(&self.stream);
+        let handle = self.app_handle.clone();
+
+        std::thread::spawn(move || {
+            let duration_ms = shared.duration_ms();
+            loop {
+                std::thread::sleep(std::time::Duration::from_millis(PROGRESS_INTERVAL_MS));
+
+                if shared.generation.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+
+                let finished = !shared.active.load(Ordering::SeqCst);
+                if shared.paused.load(Ordering::SeqCst) && !finished {
+                    continue;
+                }
+
+                let position_ms = shared.position_ms().min(duration_ms);
+                let _ = handle.emit("playback-progress", serde_json::json!({
+                    "position_ms": position_ms,
+                    "duration_ms": duration_ms,
+                    "progress": if duration_ms > 0 { position_ms as f64 / duration_ms as f64 } else { 1.0 },
+                }));
+
+                if finished {
+                    // Release the output device once the buffer has drained
+                    let mut guard = stream.lock().unwrap();
+                    if shared.generation.load(Ordering::SeqCst) == generation {
+                        *guard = None;
+                        drop(guard);
+                        tracing::info!("Playback finished ({}ms)", duration_ms);
+                        let _ = handle.emit("playback-status", serde_json::json!({"status": "idle"}));
+                    }
+                    return;
+                }
+            }
+        });
+    }
+}
+
+impl Drop for AudioPlayback {
+    fn drop(&mut self) {
+        self.halt();
     }
 }
@@ -80,6 +80,251 @@ pub fn split_at_silence(
     chunks
 }
 
+/// Energy/spectral voice activity detector settings.
+#[derive(Debug, Clone)]
+pub struct VadConfig {
+    pub frame_ms: f32,
+    /// A frame is loud enough for speech this far above the estimated noise floor.
+    pub threshold_db: f32,
+    /// The noise floor estimate is capped here, so recordings with little silence in
+    /// them do not mistake speech for background noise.
+    pub max_noise_floor_db: f32,
+    /// Spectral flatness above this (0 = tonal, ~0.56 = white noise) is treated as noise.
+    pub max_flatness: f32,
+    /// Bursts shorter than this are dropped as clicks and bumps.
+    pub min_speech_ms: f32,
+    /// Speech is held this long after the last speech frame, bridging short pauses.
+    pub hangover_ms: f32,
+    /// Added before and after each region so onsets and word endings are not clipped.
+    pub padding_ms: f32,
+}
+
+impl Default for VadConfig {
+    fn default() -> Self {
+        Self {
+            frame_ms: 30.0,
+            threshold_db: 9.0,
+            max_noise_floor_db: -45.0,
+            max_flatness: 0.45,
+            min_speech_ms: 120.0,
+            hangover_ms: 300.0,
+            padding_ms: 200.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpeechRegion {
+    pub start_sample: usize,
+    pub end_sample: usize,
+}
+
+/// Finds speech in `samples`. Each frame is classified by its energy above an adaptive
+/// noise floor and by its spectral flatness; the resulting runs are held over short
+/// pauses, filtered by length, padded and merged.
+pub fn detect_speech(samples: &[f32], sample_rate: u32, config: &VadConfig) -> Vec<SpeechRegion> {
+    let frame_len = ((config.frame_ms / 1000.0) * sample_rate as f32) as usize;
+    let frame_len = frame_len.max(1);
+    let num_frames = samples.len() / frame_len;
+    if num_frames == 0 {
+        return Vec::new();
+    }
+
+    let n_fft = frame_len.next_power_of_two();
+    let window = hann_window(frame_len);
+    let mut fft_buf = vec![0.0f32; n_fft * 2];
+
+    let mut energies_db = Vec::with_capacity(num_frames);
+    let mut flatness = Vec::with_capacity(num_frames);
+    for i in 0..num_frames {
+        let frame = &samples[i * frame_len..(i + 1) * frame_len];
+        let mean_sq = frame.iter().map(|s| s * s).sum::<f32>() / frame_len as f32;
+        energies_db.push(10.0 * (mean_sq + 1e-10).log10());
+        flatness.push(spectral_flatness(frame, &window, &mut fft_buf, n_fft));
+    }
+
+    let mut sorted = energies_db.clone();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let noise_floor = sorted[sorted.len() / 10].min(config.max_noise_floor_db);
+    let energy_threshold = noise_floor + config.threshold_db;
+
+    let frame_ms = frame_len as f32 * 1000.0 / sample_rate as f32;
+    let hangover_frames = (config.hangover_ms / frame_ms).round() as usize;
+    let min_speech_frames = ((config.min_speech_ms / frame_ms).round() as usize).max(1);
+    let padding = ((config.padding_ms / 1000.0) * sample_rate as f32) as usize;
+
+    // Runs of speech frames, with pauses up to the hangover bridged
+    let mut runs: Vec<(usize, usize, usize)> = Vec::new(); // (first frame, end frame, speech frames)
+    let mut current: Option<(usize, usize, usize)> = None;
+    for i in 0..num_frames {
+        let is_speech = energies_db[i] > energy_threshold && flatness[i] < config.max_flatness;
+        if is_speech {
+            current = match current {
+                Some((start, _, count)) => Some((start, i + 1, count + 1)),
+                None => Some((i, i + 1, 1)),
+            };
+        } else if let Some((_, end, _)) = current {
+            if i >= end + hangover_frames {
+                runs.extend(current.take());
+            }
+        }
+    }
+    runs.extend(current);
+
+    let mut regions: Vec<SpeechRegion> = Vec::new();
+    for (start, end, count) in runs {
+        if count < min_speech_frames {
+            continue;
+        }
+        let region = SpeechRegion {
+            start_sample: (start * frame_len).saturating_sub(padding),
+            end_sample: ((end + hangover_frames) * frame_len + padding).min(samples.len()),
+        };
+        match regions.last_mut() {
+            Some(last) if region.start_sample <= last.end_sample => {
+                last.end_sample = last.end_sample.max(region.end_sample);
+            }
+            _ => regions.push(region),
+        }
+    }
+    regions
+}
+
+/// How fast the live noise floor estimate creeps up, so it follows rising background noise.
+const NOISE_FLOOR_RISE_DB_PER_S: f32 = 1.0;
+
+/// Speech/silence tracking over successive microphone level readings, for a live input
+/// where [`detect_speech`] cannot see the whole signal. Uses the energy half of
+/// `VadConfig`: threshold above the noise floor, minimum speech length and hangover.
+pub struct LiveSpeechDetector {
+    config: VadConfig,
+    noise_floor_db: Option<f32>,
+    speech_run_ms: f32,
+    silence_ms: f32,
+    heard_speech: bool,
+}
+
+impl LiveSpeechDetector {
+    pub fn new(config: VadConfig) -> Self {
+        Self {
+            config,
+            noise_floor_db: None,
+            speech_run_ms: 0.0,
+            silence_ms: 0.0,
+            heard_speech: false,
+        }
+    }
+
+    /// Feeds one RMS level (0–1) covering `interval_ms`. Returns whether speech is active.
+    pub fn update(&mut self, level: f32, interval_ms: f32) -> bool {
+        let db = 20.0 * level.max(1e-5).log10();
+        let floor = match self.noise_floor_db {
+            Some(floor) if db >= floor => floor + NOISE_FLOOR_RISE_DB_PER_S * interval_ms / 1000.0,
+            _ => db,
+        };
+        self.noise_floor_db = Some(floor);
+
+        if db > floor.min(self.config.max_noise_floor_db) + self.config.threshold_db {
+            self.speech_run_ms += interval_ms;
+            if self.speech_run_ms >= self.config.min_speech_ms {
+                self.heard_speech = true;
+                self.silence_ms = 0.0;
+            }
+        } else {
+            self.speech_run_ms = 0.0;
+            self.silence_ms += interval_ms;
+        }
+        self.is_speaking()
+    }
+
+    pub fn heard_speech(&self) -> bool {
+        self.heard_speech
+    }
+
+    /// Time since speech was last heard.
+    pub fn silence_ms(&self) -> f32 {
+        self.silence_ms
+    }
+
+    pub fn is_speaking(&self) -> bool {
+        self.heard_speech && self.silence_ms < self.config.hangover_ms
+    }
+
+    /// Waits for a new utterance, keeping the noise floor learnt so far.
+    pub fn end_utterance(&mut self) {
+        self.heard_speech = false;
+        self.speech_run_ms = 0.0;
+        self.silence_ms = 0.0;
+    }
+}
+
+fn spectral_flatness(frame: &[f32], window: &[f32], fft_buf: &mut [f32], n_fft: usize) -> f32 {
+    fft_buf.fill(0.0);
+    for (i, (&s, &w)) in frame.iter().zip(window).enumerate() {
+        fft_buf[i * 2] = s * w;
+    }
+    fft_in_place(fft_buf, n_fft);
+
+    let bins = 1..n_fft / 2;
+    let count = bins.len() as f32;
+    let mut log_sum = 0.0f32;
+    let mut sum = 0.0f32;
+    for k in bins {
+        let power = fft_buf[k * 2] * fft_buf[k * 2] + fft_buf[k * 2 + 1] * fft_buf[k * 2 + 1] + 1e-12;
+        log_sum += power.ln();
+        sum += power;
+    }
+    (log_sum / count).exp() / (sum / count)
+}
+
+/// Like [`split_at_silence`], but driven by [`detect_speech`]: leading and trailing
+/// silence is dropped, consecutive speech regions are grouped into chunks of up to
+/// `target_duration_s + search_window_s`, and cuts fall between regions. A single
+/// region longer than that is split with `split_at_silence`. Returns no chunks when
+/// there is no speech.
+pub fn split_speech(
+    samples: &[f32],
+    sample_rate: u32,
+    target_duration_s: f32,
+    search_window_s: f32,
+    rms_window_ms: f32,
+    vad: &VadConfig,
+) -> Vec<ChunkBoundary> {
+    let max_chunk_samples = ((target_duration_s + search_window_s) * sample_rate as f32) as usize;
+    let regions = detect_speech(samples, sample_rate, vad);
+
+    let mut chunks: Vec<ChunkBoundary> = Vec::new();
+    let mut current: Option<ChunkBoundary> = None;
+
+    for region in regions {
+        if let Some(chunk) = current.as_mut() {
+            if region.end_sample - chunk.start_sample <= max_chunk_samples {
+                chunk.end_sample = region.end_sample;
+                continue;
+            }
+            chunks.extend(current.take());
+        }
+
+        if region.end_sample - region.start_sample > max_chunk_samples {
+            let pieces = split_at_silence(
+                &samples[region.start_sample..region.end_sample],
+                sample_rate,
+                target_duration_s,
+                search_window_s,
+                rms_window_ms,
+            );
+            chunks.extend(pieces.into_iter().map(|p| ChunkBoundary {
+                start_sample: region.start_sample + p.start_sample,
+                end_sample: region.start_sample + p.end_sample,
+            }));
+        } else {
+            current = Some(ChunkBoundary { start_sample: region.start_sample, end_sample: region.end_sample });
+        }
+    }
+    chunks.extend(current);
+    chunks
+}
+
 pub fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Result<Vec<f32>> {
     if from_rate == to_rate {
         return Ok(samples.to_vec());
@@ -108,6 +353,17 @@ pub fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Result<Vec<f32
     Ok(output.into_iter().next().unwrap_or_default())
 }
 
+/// Averages interleaved frames down to a single channel.
+pub fn downmix_to_mono(samples: &[f32], channels: u16) -> Vec<f32> {
+    if channels <= 1 {
+        return samples.to_vec();
+    }
+    samples
+        .chunks(channels as usize)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect()
+}
+
 pub fn normalize(samples: &mut [f32]) {
     let max_val = samples.iter().map(|s| s.abs()).fold(0.0f32, f32::max);
     if max_val > 0.0 && max_val != 1.0 {
@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use crate::engine::TranscriptionResult;
+use crate::export::{self, ExportFormat, ExportOptions};
+
+#[tauri::command]
+pub fn export_transcription(
+    result: TranscriptionResult,
+    format: ExportFormat,
+    path: String,
+    options: Option<ExportOptions>,
+) -> Result<(), String> {
+    export::export_to_file(&result, format, Path::new(&path), &options.unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn export_history_entry(
+    id: String,
+    format: ExportFormat,
+    path: String,
+    options: Option<ExportOptions>,
+) -> Result<(), String> {
+    let entry = crate::history::get_entry(&id).map_err(|e| e.to_string())?;
+    let result = TranscriptionResult {
+        text: entry.text,
+        language: entry.language,
+        duration_ms: entry.duration_ms,
+        segments: entry.segments,
+        words: entry.words,
+        translated: entry.translated,
+    };
+    export::export_to_file(&result, format, Path::new(&path), &options.unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
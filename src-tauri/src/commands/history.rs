@@ -0,0 +1,52 @@
+use tauri::{AppHandle, Manager};
+
+use crate::history::{self, HistoryEntry};
+
+/// Delay after hiding the settings window, so focus is back in the target app before pasting.
+const REINJECT_FOCUS_DELAY_MS: u64 = 300;
+
+#[tauri::command]
+pub fn list_history(limit: Option<usize>) -> Result<Vec<HistoryEntry>, String> {
+    history::list_entries(limit).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn search_history(query: String) -> Result<Vec<HistoryEntry>, String> {
+    history::search_entries(&query).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn delete_history_entry(id: String) -> Result<(), String> {
+    history::delete_entry(&id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn clear_history() -> Result<(), String> {
+    history::clear().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn copy_history_entry(id: String) -> Result<(), String> {
+    let entry = history::get_entry(&id).map_err(|e| e.to_string())?;
+    let injector = crate::platform::get_text_injector();
+    injector.copy_to_clipboard(&entry.text).map_err(|e| e.to_string())
+}
+
+/// Hides the settings window and injects the entry into whichever app regains focus.
+#[tauri::command]
+pub fn reinject_history_entry(app_handle: AppHandle, id: String) -> Result<(), String> {
+    let entry = history::get_entry(&id).map_err(|e| e.to_string())?;
+
+    if let Some(window) = app_handle.get_webview_window("main") {
+        let _ = window.hide();
+    }
+
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(REINJECT_FOCUS_DELAY_MS));
+        if let Err(e) = crate::commands::stt::inject_transcript(&app_handle, &entry.text) {
+            tracing::error!("Failed to re-inject history entry {}: {}", entry.id, e);
+        }
+    });
+
+    Ok(())
+}
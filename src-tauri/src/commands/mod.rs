@@ -1,4 +1,8 @@
 pub mod models;
 pub mod stt;
+pub mod transcribe;
+pub mod voice;
 pub mod tts;
 pub mod settings;
+pub mod history;
+pub mod export;
@@ -109,6 +109,14 @@ pub async fn download_model(app_handle: AppHandle, model_id: String) -> Result<(
             load_stt_engine(&app_handle, &model_id).map_err(|e| e.to_string())?;
             crate::persistence::save_settings(&app_handle);
         }
+    } else {
+        let state = app_handle.state::<crate::state::AppState>();
+        let current_active = state.settings.lock().unwrap().tts.active_model_id.clone();
+        if current_active.is_none() {
+            load_tts_engine(&app_handle, &model_id).map_err(|e| e.to_string())?;
+            crate::commands::tts::reset_tts_idle_timer(&app_handle);
+            crate::persistence::save_settings(&app_handle);
+        }
     }
 
     Ok(())
@@ -172,8 +180,8 @@ pub fn set_active_model(app_handle: AppHandle, model_id: String, capability: Str
             crate::commands::stt::reset_idle_timer(&app_handle);
         }
         "tts" => {
-            let state = app_handle.state::<crate::state::AppState>();
-            state.settings.lock().unwrap().tts.active_model_id = Some(model_id);
+            load_tts_engine(&app_handle, &model_id).map_err(|e| e.to_string())?;
+            crate::commands::tts::reset_tts_idle_timer(&app_handle);
         }
         _ => return Err("Invalid capability".into()),
     }
@@ -244,6 +252,50 @@ pub(crate) fn load_stt_engine(app_handle: &AppHandle, model_id: &str) -> anyhow:
     Ok(())
 }
 
+pub(crate) fn load_tts_engine(app_handle: &AppHandle, model_id: &str) -> anyhow::Result<()> {
+    use crate::engine::{self, ModelInfo, EngineType, TtsEngine};
+
+    let installed = registry::list_installed_models(Some(&ModelCapability::TextToSpeech))?;
+    let model = installed.iter()
+        .find(|m| m.id == model_id)
+        .ok_or_else(|| anyhow::anyhow!("Model not installed: {}", model_id))?;
+
+    let model_dir = std::path::PathBuf::from(&model.path);
+
+    let languages = registry::load_catalog()
+        .ok()
+        .and_then(|c| c.into_iter().find(|m| m.id == model_id))
+        .map(|m| m.languages)
+        .unwrap_or_default();
+
+    let info = ModelInfo {
+        id: model.id.clone(),
+        name: model.name.clone(),
+        capability: ModelCapability::TextToSpeech,
+        engine: model.engine.clone(),
+        languages,
+        size_bytes: model.size_bytes,
+    };
+
+    let engine: Box<dyn TtsEngine> = match model.engine {
+        EngineType::Onnx => {
+            let mut eng = engine::onnx_tts::OnnxTtsEngine::new();
+            eng.load_model(&model_dir, &info)?;
+            tracing::info!("ONNX TTS engine loaded: {} from {}", model_id, model_dir.display());
+            Box::new(eng)
+        }
+        EngineType::WhisperCpp => {
+            anyhow::bail!("Engine {:?} does not support text-to-speech", model.engine);
+        }
+    };
+
+    let state = app_handle.state::<crate::state::AppState>();
+    *state.active_tts_engine.lock().unwrap() = Some(engine);
+    state.settings.lock().unwrap().tts.active_model_id = Some(model_id.to_string());
+
+    Ok(())
+}
+
 fn chrono_now() -> String {
     let dur = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
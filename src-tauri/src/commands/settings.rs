@@ -1,5 +1,7 @@
 use tauri::{AppHandle, Manager};
-use crate::state::{AppState, Settings};
+use crate::state::{AppState, Hotword, Settings};
+use crate::engine::DecodingConfig;
+use crate::audio::capture::InputDeviceInfo;
 
 #[tauri::command]
 pub fn get_settings(app_handle: AppHandle) -> Result<Settings, String> {
@@ -8,6 +10,106 @@ pub fn get_settings(app_handle: AppHandle) -> Result<Settings, String> {
     Ok(settings)
 }
 
+#[tauri::command]
+pub fn get_decoding_settings(app_handle: AppHandle, model_id: String) -> Result<DecodingConfig, String> {
+    let state = app_handle.state::<AppState>();
+    let config = state.settings.lock().unwrap().stt.decoding_for(Some(&model_id));
+    Ok(config)
+}
+
+/// Stores decoder settings for one model. They apply from the next transcribed chunk.
+#[tauri::command]
+pub fn update_decoding_settings(app_handle: AppHandle, model_id: String, config: DecodingConfig) -> Result<(), String> {
+    let state = app_handle.state::<AppState>();
+    state.settings.lock().unwrap().stt.decoding.insert(model_id, config);
+    crate::persistence::save_settings(&app_handle);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn list_input_devices() -> Result<Vec<InputDeviceInfo>, String> {
+    crate::audio::capture::list_input_devices().map_err(|e| e.to_string())
+}
+
+/// Sets the input device fallback chain, most preferred first. An empty list uses the
+/// system default. Applies from the next recording; an open warm mic moves right away.
+#[tauri::command]
+pub fn set_preferred_input_devices(app_handle: AppHandle, devices: Vec<String>) -> Result<Vec<String>, String> {
+    let mut chain: Vec<String> = Vec::new();
+    for device in devices {
+        if !device.is_empty() && !chain.contains(&device) {
+            chain.push(device);
+        }
+    }
+
+    let state = app_handle.state::<AppState>();
+    let warm_mic_enabled = {
+        let mut settings = state.settings.lock().unwrap();
+        settings.stt.preferred_input_devices = chain.clone();
+        settings.stt.warm_mic.enabled
+    };
+    crate::persistence::save_settings(&app_handle);
+
+    if warm_mic_enabled {
+        crate::commands::stt::apply_warm_mic(&app_handle);
+    }
+    Ok(chain)
+}
+
+#[tauri::command]
+pub fn get_hotwords(app_handle: AppHandle) -> Result<Vec<Hotword>, String> {
+    let state = app_handle.state::<AppState>();
+    let hotwords = state.settings.lock().unwrap().stt.hotwords.clone();
+    Ok(hotwords)
+}
+
+/// Collapses whitespace in the phrase and treats an empty or "auto" language as
+/// any language, so add and remove agree on what a hotword is.
+fn normalize_hotword(phrase: &str, language: Option<String>) -> Result<Hotword, String> {
+    let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+    if phrase.is_empty() {
+        return Err("Hotword cannot be empty".to_string());
+    }
+    if phrase.chars().any(char::is_control) {
+        return Err("Hotword cannot contain control characters".to_string());
+    }
+    Ok(Hotword { phrase, language: language.filter(|l| !l.is_empty() && l != "auto") })
+}
+
+#[tauri::command]
+pub fn add_hotword(app_handle: AppHandle, phrase: String, language: Option<String>) -> Result<Vec<Hotword>, String> {
+    let hotword = normalize_hotword(&phrase, language)?;
+
+    let state = app_handle.state::<AppState>();
+    let hotwords = {
+        let mut settings = state.settings.lock().unwrap();
+        if !settings.stt.hotwords.contains(&hotword) {
+            settings.stt.hotwords.push(hotword);
+        }
+        settings.stt.hotwords.clone()
+    };
+    crate::persistence::save_settings(&app_handle);
+    Ok(hotwords)
+}
+
+#[tauri::command]
+pub fn remove_hotword(app_handle: AppHandle, phrase: String, language: Option<String>) -> Result<Vec<Hotword>, String> {
+    let hotword = normalize_hotword(&phrase, language)?;
+
+    let state = app_handle.state::<AppState>();
+    let hotwords = {
+        let mut settings = state.settings.lock().unwrap();
+        let before = settings.stt.hotwords.len();
+        settings.stt.hotwords.retain(|h| *h != hotword);
+        if settings.stt.hotwords.len() == before {
+            return Err(format!("Hotword not found: {}", hotword.phrase));
+        }
+        settings.stt.hotwords.clone()
+    };
+    crate::persistence::save_settings(&app_handle);
+    Ok(hotwords)
+}
+
 #[tauri::command]
 pub fn update_settings(app_handle: AppHandle, settings: Settings) -> Result<(), String> {
     let state = app_handle.state::<AppState>();
@@ -16,6 +118,9 @@ pub fn update_settings(app_handle: AppHandle, settings: Settings) -> Result<(),
     let stt_active = current.stt.active_model_id.clone();
     let tts_active = current.tts.active_model_id.clone();
     let old_timeout = current.stt.model_idle_timeout_s;
+    let old_tts_timeout = current.tts.model_idle_timeout_s;
+    let old_history = current.history.clone();
+    let old_warm_mic = current.stt.warm_mic.clone();
 
     *current = settings;
 
@@ -27,6 +132,9 @@ pub fn update_settings(app_handle: AppHandle, settings: Settings) -> Result<(),
     }
 
     let new_timeout = current.stt.model_idle_timeout_s;
+    let new_tts_timeout = current.tts.model_idle_timeout_s;
+    let new_history = current.history.clone();
+    let new_warm_mic = current.stt.warm_mic.clone();
     drop(current);
 
     crate::persistence::save_settings(&app_handle);
@@ -48,6 +156,24 @@ pub fn update_settings(app_handle: AppHandle, settings: Settings) -> Result<(),
         }
     }
 
+    if old_tts_timeout != new_tts_timeout {
+        if new_tts_timeout.is_none() {
+            crate::commands::tts::cancel_tts_idle_timer(&app_handle);
+        } else {
+            crate::commands::tts::reset_tts_idle_timer(&app_handle);
+        }
+    }
+
+    if old_history != new_history {
+        if let Err(e) = crate::history::prune(&new_history) {
+            tracing::error!("Failed to apply history retention: {}", e);
+        }
+    }
+
+    if old_warm_mic != new_warm_mic {
+        crate::commands::stt::apply_warm_mic(&app_handle);
+    }
+
     Ok(())
 }
 
@@ -61,6 +187,26 @@ pub fn update_stt_shortcut(app_handle: AppHandle, shortcut: String) -> Result<()
     crate::hotkey::update_stt_shortcut(&app_handle, &shortcut).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub fn update_translate_shortcut(app_handle: AppHandle, shortcut: Option<String>) -> Result<(), String> {
+    let state = app_handle.state::<AppState>();
+    let status = state.status.lock().unwrap().clone();
+    if status == crate::state::AppStatus::Recording {
+        return Err("Cannot change shortcut while recording".to_string());
+    }
+    crate::hotkey::update_translate_shortcut(&app_handle, shortcut.as_deref()).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn update_tts_shortcut(app_handle: AppHandle, shortcut: String) -> Result<(), String> {
+    let state = app_handle.state::<AppState>();
+    let status = state.status.lock().unwrap().clone();
+    if matches!(status, crate::state::AppStatus::Synthesizing | crate::state::AppStatus::Playing) {
+        return Err("Cannot change shortcut while reading aloud".to_string());
+    }
+    crate::hotkey::update_tts_shortcut(&app_handle, &shortcut).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub fn check_accessibility_permission() -> Result<bool, String> {
     let injector = crate::platform::get_text_injector();
@@ -80,6 +226,13 @@ pub fn get_stt_shortcut_label(app_handle: AppHandle) -> String {
     crate::hotkey::shortcut_display_label(&shortcut).to_string()
 }
 
+#[tauri::command]
+pub fn get_tts_shortcut_label(app_handle: AppHandle) -> String {
+    let state = app_handle.state::<AppState>();
+    let shortcut = state.settings.lock().unwrap().shortcuts.tts.clone();
+    crate::hotkey::shortcut_display_label(&shortcut).to_string()
+}
+
 #[tauri::command]
 pub fn get_app_version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
@@ -2,10 +2,13 @@ use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
 use anyhow::Result;
 
-use crate::state::{AppState, AppStatus, StreamingState};
+use crate::state::{AppState, AppStatus, AutoStopSettings, RecordingMode, StreamingState};
 use crate::audio::AudioCapture;
-use crate::audio::processing::{split_at_silence, resample};
-use crate::engine::{AudioBuffer, TranscriptionResult};
+use crate::audio::capture::{LevelMonitor, WarmMic};
+use crate::audio::processing::{split_at_silence, split_speech, resample, ChunkBoundary, LiveSpeechDetector, VadConfig};
+use crate::engine::{
+    AudioBuffer, DecodingProfile, EngineType, ModelCapability, Segment, TranscribeOptions, TranscriptionResult, Word,
+};
 use crate::platform;
 
 const STREAMING_CHUNK_DURATION_S: f32 = 20.0;
@@ -13,12 +16,20 @@ const STREAMING_SEARCH_WINDOW_S: f32 = 2.0;
 const STREAMING_RMS_WINDOW_MS: f32 = 100.0;
 const STREAMING_POLL_INTERVAL_MS: u64 = 500;
 const TARGET_SAMPLE_RATE: u32 = 16000;
+const AUTO_STOP_POLL_MS: u64 = 100;
+const WARM_MIC_MIN_PRE_ROLL_MS: u64 = 300;
+const WARM_MIC_MAX_PRE_ROLL_MS: u64 = 1000;
+const DEVICE_WATCH_POLL_MS: u64 = 250;
+/// An input device that delivers nothing for this long is treated as disconnected.
+const DEVICE_STALL_TIMEOUT_MS: u64 = 2000;
+/// Auto-stop countdown events are only sent for this final stretch.
+const AUTO_STOP_COUNTDOWN_MS: f32 = 3000.0;
 
 /// Joins chunk text to accumulated text, smoothing artificial punctuation at boundaries.
 /// When the previous chunk ends with a sentence-ending punct (`.!?`) and the new chunk
 /// starts with a lowercase letter, the punct was likely added by the model because it saw
 /// the end of the audio segment — not a real sentence boundary. We remove it.
-fn append_chunk_text(accumulated: &mut String, new_chunk: &str) {
+pub(crate) fn append_chunk_text(accumulated: &mut String, new_chunk: &str) {
     let new_chunk = new_chunk.trim();
     if new_chunk.is_empty() {
         return;
@@ -41,6 +52,293 @@ fn append_chunk_text(accumulated: &mut String, new_chunk: &str) {
     accumulated.push_str(new_chunk);
 }
 
+/// The part of `accumulated` not yet injected, holding back a trailing `.!?` that
+/// `append_chunk_text` may still remove. Everything returned is final, so the
+/// injected text is always a prefix of the end result.
+fn take_live_delta(accumulated: &str, injected_len: &mut usize) -> Option<String> {
+    let stable_len = if accumulated.ends_with(['.', '!', '?']) {
+        accumulated.len() - 1
+    } else {
+        accumulated.len()
+    };
+    if stable_len <= *injected_len {
+        return None;
+    }
+    let delta = accumulated[*injected_len..stable_len].to_string();
+    *injected_len = stable_len;
+    Some(delta)
+}
+
+/// Places a chunk's segments on the recording timeline by shifting them by the chunk's
+/// start. Engines without timestamps get one segment spanning the whole chunk.
+pub(crate) fn timeline_segments(result: &TranscriptionResult, chunk_start_ms: u64, chunk_end_ms: u64) -> Vec<Segment> {
+    match result.segments.as_ref() {
+        Some(segs) => segs.iter()
+            .map(|s| Segment {
+                start_ms: s.start_ms + chunk_start_ms,
+                end_ms: s.end_ms + chunk_start_ms,
+                text: s.text.clone(),
+            })
+            .collect(),
+        None if !result.text.trim().is_empty() => vec![Segment {
+            start_ms: chunk_start_ms,
+            end_ms: chunk_end_ms,
+            text: result.text.clone(),
+        }],
+        None => Vec::new(),
+    }
+}
+
+/// Shifts a chunk's word timings by the chunk's start in the recording.
+pub(crate) fn timeline_words(result: &TranscriptionResult, chunk_start_ms: u64) -> Vec<Word> {
+    result.words.iter()
+        .flatten()
+        .map(|w| Word {
+            start_ms: w.start_ms + chunk_start_ms,
+            end_ms: w.end_ms + chunk_start_ms,
+            text: w.text.clone(),
+            confidence: w.confidence,
+        })
+        .collect()
+}
+
+/// Language and decoder settings for the active model, from the current settings.
+/// With "auto", `locked_language` (the language detected on an earlier chunk of the
+/// same recording) is used so every chunk is decoded alike.
+pub(crate) fn transcribe_options(
+    app_handle: &AppHandle,
+    profile: DecodingProfile,
+    locked_language: Option<&str>,
+) -> TranscribeOptions {
+    let state = app_handle.state::<AppState>();
+    let settings = state.settings.lock().unwrap();
+    let lang = settings.stt.language.clone();
+    let language = if lang == "auto" { locked_language.map(|l| l.to_string()) } else { Some(lang) };
+    TranscribeOptions {
+        hotwords: settings.stt.hotwords_for(language.as_deref()),
+        hotword_boost: settings.stt.hotword_boost,
+        allowed_languages: settings.stt.allowed_languages.clone(),
+        language,
+        decoding: settings.stt.decoding_for(settings.stt.active_model_id.as_deref()),
+        profile,
+        translate: false,
+    }
+}
+
+/// Chunks 16 kHz audio for transcription. With VAD enabled, silence is left out and
+/// cuts fall between utterances; otherwise cuts are at the quietest point near each
+/// target length.
+pub(crate) fn split_chunks(
+    app_handle: &AppHandle,
+    samples: &[f32],
+    target_duration_s: f32,
+    search_window_s: f32,
+    rms_window_ms: f32,
+) -> Vec<ChunkBoundary> {
+    let vad_enabled = app_handle.state::<AppState>().settings.lock().unwrap().stt.vad_enabled;
+    if vad_enabled {
+        split_speech(
+            samples,
+            TARGET_SAMPLE_RATE,
+            target_duration_s,
+            search_window_s,
+            rms_window_ms,
+            &VadConfig::default(),
+        )
+    } else {
+        split_at_silence(samples, TARGET_SAMPLE_RATE, target_duration_s, search_window_s, rms_window_ms)
+    }
+}
+
+fn samples_to_ms(samples: usize, sample_rate: u32) -> u64 {
+    samples as u64 * 1000 / sample_rate as u64
+}
+
+/// Starts capturing from the warm mic when it is open, so the recording begins with its
+/// pre-roll, or from a freshly opened input stream otherwise.
+pub(crate) fn open_capture(app_handle: &AppHandle) -> Result<AudioCapture> {
+    let state = app_handle.state::<AppState>();
+    let preferred = state.settings.lock().unwrap().stt.preferred_input_devices.clone();
+
+    let warm_lost = state.warm_mic.lock().unwrap().as_ref().is_some_and(|warm| warm.is_lost());
+    if warm_lost {
+        tracing::warn!("Warm mic device is gone, reopening");
+        apply_warm_mic(app_handle);
+    }
+
+    let mut capture = AudioCapture::new()?;
+    let warm = state.warm_mic.lock().unwrap().clone();
+    match warm {
+        Some(warm) => capture.start_warm(warm, &preferred),
+        None => capture.start(&preferred)?,
+    }
+    Ok(capture)
+}
+
+/// Watches a recording's input device. When it disconnects or stops delivering audio,
+/// the capture fails over to the next device and `device-changed` is emitted.
+pub(crate) struct DeviceWatch {
+    frames_received: usize,
+    last_progress: std::time::Instant,
+    /// A stall was found with no other device to move to; it is not reported again.
+    stall_reported: bool,
+}
+
+impl DeviceWatch {
+    pub(crate) fn new() -> Self {
+        Self {
+            frames_received: 0,
+            last_progress: std::time::Instant::now(),
+            stall_reported: false,
+        }
+    }
+
+    /// Returns false when the device is gone and nothing can replace it, in which case
+    /// the caller should end the recording. A stalled device with no alternative is
+    /// kept, as it may recover.
+    pub(crate) fn check(&mut self, app_handle: &AppHandle, capture: &mut AudioCapture) -> bool {
+        let frames = capture.frames_received();
+        if frames != self.frames_received {
+            self.frames_received = frames;
+            self.last_progress = std::time::Instant::now();
+            self.stall_reported = false;
+        }
+        let lost = capture.device_lost();
+        let stalled = self.last_progress.elapsed().as_millis() as u64 >= DEVICE_STALL_TIMEOUT_MS;
+        if !lost && !stalled {
+            return true;
+        }
+        // Looked at again once the stall timeout runs out
+        self.last_progress = std::time::Instant::now();
+
+        let previous = capture.device_name().map(|name| name.to_string());
+        match capture.failover() {
+            Ok(device) => {
+                self.stall_reported = false;
+                let _ = app_handle.emit("device-changed", serde_json::json!({
+                    "device": device,
+                    "previous": previous,
+                }));
+                true
+            }
+            Err(e) if lost => {
+                tracing::error!(
+                    "Input device {} disconnected and there is no other: {}",
+                    previous.as_deref().unwrap_or("unknown"), e
+                );
+                let _ = app_handle.emit("device-changed", serde_json::json!({
+                    "device": null,
+                    "previous": previous,
+                }));
+                false
+            }
+            Err(e) => {
+                if !self.stall_reported {
+                    tracing::warn!(
+                        "Input device {} stopped delivering audio, keeping it: {}",
+                        previous.as_deref().unwrap_or("unknown"), e
+                    );
+                    self.stall_reported = true;
+                }
+                true
+            }
+        }
+    }
+}
+
+/// Ends the recording if its device disconnects with nothing to replace it, keeping
+/// what was recorded.
+fn device_watch_loop(app_handle: AppHandle) {
+    let mut watch = DeviceWatch::new();
+    loop {
+        std::thread::sleep(std::time::Duration::from_millis(DEVICE_WATCH_POLL_MS));
+        let state = app_handle.state::<AppState>();
+        let device_ok = {
+            let mut capture_guard = state.audio_capture.lock().unwrap();
+            match capture_guard.as_mut() {
+                Some(capture) if capture.is_recording() => watch.check(&app_handle, capture),
+                _ => break,
+            }
+        };
+        if !device_ok {
+            let handle = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = do_stop_recording(&handle).await {
+                    tracing::error!("Error stopping recording after device loss: {}", e);
+                }
+            });
+            break;
+        }
+    }
+}
+
+/// Opens or closes the warm mic to match the settings, then updates the tray and
+/// emits `warm-mic-status` so the microphone being on is always visible.
+pub(crate) fn apply_warm_mic(app_handle: &AppHandle) {
+    let state = app_handle.state::<AppState>();
+    let (settings, preferred) = {
+        let settings = state.settings.lock().unwrap();
+        (settings.stt.warm_mic.clone(), settings.stt.preferred_input_devices.clone())
+    };
+
+    let active = {
+        let mut warm = state.warm_mic.lock().unwrap();
+        // Reopened rather than resized, so a shorter pre-roll takes effect immediately
+        *warm = None;
+        if settings.enabled {
+            let pre_roll_ms = settings.pre_roll_ms.clamp(WARM_MIC_MIN_PRE_ROLL_MS, WARM_MIC_MAX_PRE_ROLL_MS);
+            match WarmMic::open(pre_roll_ms, &preferred) {
+                Ok(mic) => *warm = Some(std::sync::Arc::new(mic)),
+                Err(e) => tracing::error!("Failed to open warm mic: {}", e),
+            }
+        }
+        warm.is_some()
+    };
+
+    if let Some(item) = state.tray_warm_mic_item.lock().unwrap().as_ref() {
+        let label = if active { "  Warm mic: On (kept in memory only)" } else { "  Warm mic: Off" };
+        let _ = item.set_text(label);
+    }
+    let _ = app_handle.emit("warm-mic-status", serde_json::json!({"active": active}));
+}
+
+#[tauri::command]
+pub fn get_warm_mic_status(app_handle: AppHandle) -> Result<bool, String> {
+    Ok(app_handle.state::<AppState>().warm_mic.lock().unwrap().is_some())
+}
+
+/// Injects `text` into the focused app using the configured injection mode.
+pub(crate) fn inject_transcript(app_handle: &AppHandle, text: &str) -> Result<()> {
+    let state = app_handle.state::<AppState>();
+    let injector = platform::get_text_injector();
+    let (injection_mode, restore_after) = {
+        let settings = state.settings.lock().unwrap();
+        let restore_after = settings.stt.restore_clipboard
+            .then(|| std::time::Duration::from_millis(settings.stt.clipboard_restore_delay_ms));
+        (settings.stt.injection_mode.clone(), restore_after)
+    };
+    let accessibility = injector.is_accessibility_granted();
+    tracing::info!(
+        "Text injection: mode={:?}, accessibility={}, text='{}'",
+        injection_mode, accessibility, text
+    );
+
+    match injection_mode {
+        crate::state::InjectionMode::Keystroke => {
+            if accessibility {
+                injector.inject_text(text)?;
+            } else {
+                tracing::warn!("Keystroke mode but no accessibility — falling back to clipboard-only");
+                injector.inject_via_clipboard(text, restore_after)?;
+            }
+        }
+        crate::state::InjectionMode::Clipboard => {
+            injector.inject_via_clipboard(text, restore_after)?;
+        }
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub fn start_recording(app_handle: AppHandle) -> Result<(), String> {
     do_start_recording(&app_handle).map_err(|e| e.to_string())
@@ -59,6 +357,12 @@ pub fn get_status(app_handle: AppHandle) -> Result<String, String> {
 }
 
 pub fn do_start_recording(app_handle: &AppHandle) -> Result<()> {
+    let translate = app_handle.state::<AppState>().settings.lock().unwrap().stt.translate_to_english;
+    do_start_recording_session(app_handle, translate)
+}
+
+/// Starts recording; with `translate`, the session's text is translated to English.
+pub fn do_start_recording_session(app_handle: &AppHandle, translate: bool) -> Result<()> {
     let state = app_handle.state::<AppState>();
 
     let engine_loaded = state.active_stt_engine.lock().unwrap().is_some();
@@ -68,18 +372,24 @@ pub fn do_start_recording(app_handle: &AppHandle) -> Result<()> {
         anyhow::bail!("No STT model selected. Please select a model in Settings.");
     }
 
+    if translate {
+        if let Some(ref mid) = model_id {
+            ensure_translation_supported(mid)?;
+        }
+    }
+
     cancel_idle_timer(app_handle);
 
-    let (monitor, drain) = {
+    let (monitor, watch_monitor, drain) = {
         let mut status = state.status.lock().unwrap();
         if *status != AppStatus::Idle {
             anyhow::bail!("Cannot start recording: app is not idle (current: {:?})", *status);
         }
 
         let mut capture_guard = state.audio_capture.lock().unwrap();
-        let mut capture = AudioCapture::new()?;
-        capture.start()?;
+        let capture = open_capture(app_handle)?;
         let monitor = capture.level_monitor();
+        let watch_monitor = capture.level_monitor();
         let drain = capture.streaming_drain();
         *capture_guard = Some(capture);
 
@@ -88,12 +398,12 @@ pub fn do_start_recording(app_handle: &AppHandle) -> Result<()> {
         } else {
             *status = AppStatus::Loading;
         }
-        (monitor, drain)
+        (monitor, watch_monitor, drain)
     };
 
     {
         let mut streaming = state.streaming_state.lock().unwrap();
-        *streaming = Some(StreamingState::default());
+        *streaming = Some(StreamingState { translate, ..StreamingState::default() });
     }
 
     let handle = app_handle.clone();
@@ -105,6 +415,18 @@ pub fn do_start_recording(app_handle: &AppHandle) -> Result<()> {
         }
     });
 
+    let handle = app_handle.clone();
+    std::thread::spawn(move || device_watch_loop(handle));
+
+    let (recording_mode, auto_stop) = {
+        let settings = state.settings.lock().unwrap();
+        (settings.stt.recording_mode.clone(), settings.stt.auto_stop.clone())
+    };
+    if recording_mode == RecordingMode::Toggle && auto_stop.enabled {
+        let handle = app_handle.clone();
+        std::thread::spawn(move || auto_stop_watch(handle, watch_monitor, auto_stop));
+    }
+
     let handle_streaming = app_handle.clone();
     let needs_load = !engine_loaded;
     let model_id_for_load = model_id;
@@ -147,14 +469,93 @@ pub fn do_start_recording(app_handle: &AppHandle) -> Result<()> {
     } else {
         let _ = app_handle.emit("recording-status", serde_json::json!({"status": "loading"}));
     }
-    let _ = app_handle.emit("overlay-mode", serde_json::json!({"mode": "stt"}));
+    let _ = app_handle.emit("overlay-mode", serde_json::json!({"mode": "stt", "translate": translate}));
 
     let handle_for_shortcut = app_handle.clone();
     std::thread::spawn(move || {
         register_cancel_shortcut(&handle_for_shortcut);
     });
 
-    tracing::info!("Recording started (engine_loaded={})", engine_loaded);
+    tracing::info!("Recording started (engine_loaded={}, translate={})", engine_loaded, translate);
+    Ok(())
+}
+
+/// Watches the input level of a toggle-mode recording. Stops it after `silence_s` of
+/// silence following speech, or cancels it if nothing is said within
+/// `no_speech_timeout_s`. Emits `auto-stop-countdown` during the last seconds, and
+/// `remaining_ms: null` when speech resumes.
+fn auto_stop_watch(app_handle: AppHandle, monitor: LevelMonitor, settings: AutoStopSettings) {
+    let mut detector = LiveSpeechDetector::new(VadConfig::default());
+    let started = std::time::Instant::now();
+    let silence_limit_ms = settings.silence_s * 1000.0;
+    let mut counting_down = false;
+
+    while monitor.is_active() {
+        std::thread::sleep(std::time::Duration::from_millis(AUTO_STOP_POLL_MS));
+        detector.update(monitor.current_level(), AUTO_STOP_POLL_MS as f32);
+
+        let (reason, remaining_ms) = if detector.heard_speech() {
+            ("silence", silence_limit_ms - detector.silence_ms())
+        } else if let Some(timeout_s) = settings.no_speech_timeout_s {
+            ("no_speech", timeout_s * 1000.0 - started.elapsed().as_millis() as f32)
+        } else {
+            continue;
+        };
+
+        if remaining_ms > AUTO_STOP_COUNTDOWN_MS {
+            if counting_down {
+                counting_down = false;
+                let _ = app_handle.emit("auto-stop-countdown", serde_json::json!({
+                    "reason": reason,
+                    "remaining_ms": null,
+                }));
+            }
+            continue;
+        }
+
+        if remaining_ms > 0.0 {
+            counting_down = true;
+            let _ = app_handle.emit("auto-stop-countdown", serde_json::json!({
+                "reason": reason,
+                "remaining_ms": remaining_ms as u64,
+            }));
+            continue;
+        }
+
+        if !monitor.is_active() {
+            break;
+        }
+        if reason == "no_speech" {
+            tracing::info!("Auto-stop: no speech within {:?}s, cancelling", settings.no_speech_timeout_s);
+            if let Err(e) = do_cancel_recording(&app_handle) {
+                tracing::error!("Auto-stop cancel failed: {}", e);
+            }
+        } else {
+            tracing::info!("Auto-stop: {}s of silence, stopping", settings.silence_s);
+            crate::hotkey::play_feedback_sound(&app_handle, "stop");
+            let handle = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = do_stop_recording(&handle).await {
+                    tracing::error!("Auto-stop failed: {}", e);
+                }
+            });
+        }
+        break;
+    }
+}
+
+/// Only Whisper can translate; other engines would silently transcribe instead.
+pub(crate) fn ensure_translation_supported(model_id: &str) -> Result<()> {
+    let installed = crate::hub::registry::list_installed_models(Some(&ModelCapability::SpeechToText))?;
+    let model = installed.iter()
+        .find(|m| m.id == model_id)
+        .ok_or_else(|| anyhow::anyhow!("Model not installed: {}", model_id))?;
+    if model.engine != EngineType::WhisperCpp {
+        anyhow::bail!(
+            "{} cannot translate. Select a Whisper model to dictate in English.",
+            model.name
+        );
+    }
     Ok(())
 }
 
@@ -186,10 +587,11 @@ fn streaming_transcription_loop(
             continue;
         }
 
-        let raw = drain.drain();
+        let (drain_offset, raw) = drain.drain();
         if raw.is_empty() {
             continue;
         }
+        let drain_offset_ms = samples_to_ms(drain_offset, device_rate);
 
         let resampled = match resample(&raw, device_rate, TARGET_SAMPLE_RATE) {
             Ok(r) => r,
@@ -199,9 +601,9 @@ fn streaming_transcription_loop(
             }
         };
 
-        let chunks = split_at_silence(
+        let chunks = split_chunks(
+            &app_handle,
             &resampled,
-            TARGET_SAMPLE_RATE,
             STREAMING_CHUNK_DURATION_S,
             STREAMING_SEARCH_WINDOW_S,
             STREAMING_RMS_WINDOW_MS,
@@ -221,17 +623,14 @@ fn streaming_transcription_loop(
                 channels: 1,
             };
 
-            {
-                let streaming = state.streaming_state.lock().unwrap();
-                if streaming.is_none() {
-                    break; // cancelled
-                }
-            }
+            let (translate, locked_language) = match state.streaming_state.lock().unwrap().as_ref() {
+                Some(s) => (s.translate, s.locked_language.clone()),
+                None => break, // cancelled
+            };
 
-            let language = {
-                let settings = state.settings.lock().unwrap();
-                let lang = settings.stt.language.clone();
-                if lang == "auto" { None } else { Some(lang) }
+            let options = TranscribeOptions {
+                translate,
+                ..transcribe_options(&app_handle, DecodingProfile::Live, locked_language.as_deref())
             };
 
             let chunk_result = {
@@ -243,7 +642,7 @@ fn streaming_transcription_loop(
                         break;
                     }
                 };
-                match engine.transcribe(&chunk_audio, language.as_deref()) {
+                match engine.transcribe(&chunk_audio, &options) {
                     Ok(r) => r,
                     Err(e) => {
                         tracing::error!("Streaming transcription error: {}", e);
@@ -257,6 +656,9 @@ fn streaming_transcription_loop(
                 chunk_result.text, chunk_result.duration_ms, chunk_result.language
             );
 
+            let live_typing = state.settings.lock().unwrap().stt.live_typing;
+            let mut live_delta = None;
+
             {
                 let mut streaming = state.streaming_state.lock().unwrap();
                 if let Some(ref mut s) = *streaming {
@@ -269,9 +671,13 @@ fn streaming_transcription_loop(
 
                     append_chunk_text(&mut s.completed_text, &chunk_result.text);
 
-                    if let Some(segs) = chunk_result.segments {
-                        s.segments.extend(segs);
-                    }
+                    let chunk_start_ms = drain_offset_ms + samples_to_ms(chunk.start_sample, TARGET_SAMPLE_RATE);
+                    s.segments.extend(timeline_segments(
+                        &chunk_result,
+                        chunk_start_ms,
+                        drain_offset_ms + samples_to_ms(chunk.end_sample, TARGET_SAMPLE_RATE),
+                    ));
+                    s.words.extend(timeline_words(&chunk_result, chunk_start_ms));
 
                     s.total_duration_ms += chunk_result.duration_ms;
                     s.chunks_completed += 1;
@@ -280,6 +686,23 @@ fn streaming_transcription_loop(
                         "chunks_completed": s.chunks_completed,
                         "text": s.completed_text,
                     }));
+
+                    if live_typing {
+                        let injected_before = s.injected_len;
+                        live_delta = take_live_delta(&s.completed_text, &mut s.injected_len)
+                            .map(|delta| (delta, injected_before));
+                    }
+                }
+            }
+
+            if let Some((delta, injected_before)) = live_delta {
+                if let Err(e) = inject_transcript(&app_handle, &delta) {
+                    tracing::error!("Live typing injection failed: {}", e);
+                    // Hand the text back so it is injected with the next chunk or at stop
+                    let mut streaming = state.streaming_state.lock().unwrap();
+                    if let Some(ref mut s) = *streaming {
+                        s.injected_len = injected_before;
+                    }
                 }
             }
         }
@@ -296,13 +719,14 @@ pub async fn do_stop_recording(app_handle: &AppHandle) -> Result<String> {
     let state = app_handle.state::<AppState>();
 
     // Stop capture — sets is_recording=false, returns only samples accumulated since last drain
-    let tail_raw = {
+    let (tail_raw, tail_offset_ms) = {
         let mut capture_guard = state.audio_capture.lock().unwrap();
         let capture = capture_guard.as_mut()
             .ok_or_else(|| anyhow::anyhow!("No active recording"))?;
         let buffer = capture.stop()?;
+        let tail_offset_ms = samples_to_ms(capture.drained_samples(), capture.device_sample_rate());
         *capture_guard = None;
-        buffer
+        (buffer, tail_offset_ms)
     };
 
     // Wait for streaming thread to finish its in-flight chunk before taking results
@@ -325,24 +749,20 @@ pub async fn do_stop_recording(app_handle: &AppHandle) -> Result<String> {
     }
     let _ = app_handle.emit("recording-status", serde_json::json!({"status": "transcribing"}));
 
+    let injected_len = streaming.injected_len;
     let mut full_text = streaming.completed_text;
     let mut all_segments = streaming.segments;
+    let mut all_words = streaming.words;
     let mut total_duration_ms = streaming.total_duration_ms;
     let mut result_language = streaming.locked_language.clone();
 
-    let language = {
-        let settings = state.settings.lock().unwrap();
-        let lang = settings.stt.language.clone();
-        if lang == "auto" { None } else { Some(lang) }
-    };
-
     // Transcribe the tail (samples since last drain — already resampled by capture.stop())
     let tail_samples = tail_raw.samples;
 
     if !tail_samples.is_empty() {
-        let tail_chunks = split_at_silence(
+        let tail_chunks = split_chunks(
+            app_handle,
             &tail_samples,
-            TARGET_SAMPLE_RATE,
             STREAMING_CHUNK_DURATION_S,
             STREAMING_SEARCH_WINDOW_S,
             STREAMING_RMS_WINDOW_MS,
@@ -354,6 +774,11 @@ pub async fn do_stop_recording(app_handle: &AppHandle) -> Result<String> {
         );
 
         for chunk in &tail_chunks {
+            let options = TranscribeOptions {
+                translate: streaming.translate,
+                ..transcribe_options(app_handle, DecodingProfile::Live, result_language.as_deref())
+            };
+
             let chunk_audio = AudioBuffer {
                 samples: tail_samples[chunk.start_sample..chunk.end_sample].to_vec(),
                 sample_rate: TARGET_SAMPLE_RATE,
@@ -364,16 +789,20 @@ pub async fn do_stop_recording(app_handle: &AppHandle) -> Result<String> {
                 let engine_guard = state.active_stt_engine.lock().unwrap();
                 let engine = engine_guard.as_ref()
                     .ok_or_else(|| anyhow::anyhow!("No STT model loaded"))?;
-                engine.transcribe(&chunk_audio, language.as_deref())?
+                engine.transcribe(&chunk_audio, &options)?
             };
 
             tracing::info!("Tail chunk: '{}' ({}ms)", chunk_result.text, chunk_result.duration_ms);
 
             append_chunk_text(&mut full_text, &chunk_result.text);
 
-            if let Some(segs) = chunk_result.segments {
-                all_segments.extend(segs);
-            }
+            let chunk_start_ms = tail_offset_ms + samples_to_ms(chunk.start_sample, TARGET_SAMPLE_RATE);
+            all_segments.extend(timeline_segments(
+                &chunk_result,
+                chunk_start_ms,
+                tail_offset_ms + samples_to_ms(chunk.end_sample, TARGET_SAMPLE_RATE),
+            ));
+            all_words.extend(timeline_words(&chunk_result, chunk_start_ms));
 
             total_duration_ms += chunk_result.duration_ms;
             if result_language.is_none() {
@@ -398,32 +827,19 @@ pub async fn do_stop_recording(app_handle: &AppHandle) -> Result<String> {
         language: result_language,
         duration_ms: total_duration_ms,
         segments: if all_segments.is_empty() { None } else { Some(all_segments) },
+        words: if all_words.is_empty() { None } else { Some(all_words) },
+        translated: streaming.translate,
     };
 
     tracing::info!("Transcription complete: '{}' ({}ms)", result.text, result.duration_ms);
 
-    let injector = platform::get_text_injector();
-    let injection_mode = {
-        state.settings.lock().unwrap().stt.injection_mode.clone()
-    };
-    let accessibility = injector.is_accessibility_granted();
-    tracing::info!(
-        "Text injection: mode={:?}, accessibility={}, text='{}'",
-        injection_mode, accessibility, result.text
-    );
+    // Saved before injecting so the text survives a failed or misdirected paste
+    record_history(app_handle, &result);
 
-    match injection_mode {
-        crate::state::InjectionMode::Keystroke => {
-            if accessibility {
-                injector.inject_text(&result.text)?;
-            } else {
-                tracing::warn!("Keystroke mode but no accessibility — falling back to clipboard-only");
-                injector.inject_via_clipboard(&result.text)?;
-            }
-        }
-        crate::state::InjectionMode::Clipboard => {
-            injector.inject_via_clipboard(&result.text)?;
-        }
+    // With live typing, earlier chunks are already in the focused app: only append the rest
+    let remaining = result.text.get(injected_len..).unwrap_or_default();
+    if !remaining.is_empty() {
+        inject_transcript(app_handle, remaining)?;
     }
 
     platform::get_media_controller().resume();
@@ -455,6 +871,26 @@ pub async fn do_stop_recording(app_handle: &AppHandle) -> Result<String> {
     Ok(result.text)
 }
 
+pub(crate) fn record_history(app_handle: &AppHandle, result: &TranscriptionResult) {
+    if result.text.trim().is_empty() {
+        return;
+    }
+    let state = app_handle.state::<AppState>();
+    let (history_settings, model_id) = {
+        let settings = state.settings.lock().unwrap();
+        (settings.history.clone(), settings.stt.active_model_id.clone())
+    };
+    if !history_settings.enabled {
+        return;
+    }
+    match crate::history::add_entry(result, model_id, &history_settings) {
+        Ok(entry) => {
+            let _ = app_handle.emit("history-updated", serde_json::json!({"id": entry.id}));
+        }
+        Err(e) => tracing::error!("Failed to save transcription to history: {}", e),
+    }
+}
+
 pub fn do_cancel_recording(app_handle: &AppHandle) -> Result<()> {
     let state = app_handle.state::<AppState>();
 
@@ -0,0 +1,174 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Emitter, Manager};
+use anyhow::Result;
+
+use crate::state::{AppState, AppStatus, CancelFlag};
+use crate::audio::decode::decode_file;
+use crate::audio::processing::{downmix_to_mono, resample};
+use crate::engine::{AudioBuffer, DecodingProfile, Segment, TranscriptionResult, Word};
+use crate::commands::stt::{split_chunks, timeline_segments, timeline_words};
+
+// Whisper sees at most 30s per window; cut a bit earlier at the quietest point
+const FILE_CHUNK_DURATION_S: f32 = 28.0;
+const FILE_SEARCH_WINDOW_S: f32 = 2.0;
+const FILE_RMS_WINDOW_MS: f32 = 100.0;
+const TARGET_SAMPLE_RATE: u32 = 16000;
+
+#[tauri::command]
+pub async fn transcribe_file(app_handle: AppHandle, path: String) -> Result<TranscriptionResult, String> {
+    tauri::async_runtime::spawn_blocking(move || do_transcribe_file(&app_handle, Path::new(&path)))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn cancel_file_transcription(app_handle: AppHandle) -> Result<(), String> {
+    let state = app_handle.state::<AppState>();
+    if let Some(flag) = state.file_transcription_cancel.lock().unwrap().as_ref() {
+        flag.store(true, Ordering::Relaxed);
+        tracing::info!("File transcription cancellation requested");
+    }
+    Ok(())
+}
+
+/// Decodes `path`, splits it at silences and transcribes it chunk by chunk with the
+/// active STT engine. Emits `transcription-progress` before each chunk.
+pub fn do_transcribe_file(app_handle: &AppHandle, path: &Path) -> Result<TranscriptionResult> {
+    let state = app_handle.state::<AppState>();
+
+    let engine_loaded = state.active_stt_engine.lock().unwrap().is_some();
+    let model_id = state.settings.lock().unwrap().stt.active_model_id.clone();
+
+    if !engine_loaded && model_id.is_none() {
+        anyhow::bail!("No STT model selected. Please select a model in Settings.");
+    }
+
+    {
+        let mut status = state.status.lock().unwrap();
+        if *status != AppStatus::Idle {
+            anyhow::bail!("Cannot transcribe file: app is not idle (current: {:?})", *status);
+        }
+        *status = AppStatus::Transcribing;
+    }
+
+    crate::commands::stt::cancel_idle_timer(app_handle);
+
+    let cancel_flag: CancelFlag = Arc::new(AtomicBool::new(false));
+    *state.file_transcription_cancel.lock().unwrap() = Some(cancel_flag.clone());
+
+    let result = run_file_transcription(app_handle, path, model_id, &cancel_flag);
+
+    *state.file_transcription_cancel.lock().unwrap() = None;
+    *state.status.lock().unwrap() = AppStatus::Idle;
+
+    {
+        let engine_guard = state.active_stt_engine.lock().unwrap();
+        if let Some(engine) = engine_guard.as_ref() {
+            if let Err(e) = engine.cool_down() {
+                tracing::error!("Engine cool_down failed: {}", e);
+            }
+        }
+    }
+    crate::commands::stt::reset_idle_timer(app_handle);
+
+    result
+}
+
+fn run_file_transcription(
+    app_handle: &AppHandle,
+    path: &Path,
+    model_id: Option<String>,
+    cancel_flag: &AtomicBool,
+) -> Result<TranscriptionResult> {
+    let state = app_handle.state::<AppState>();
+
+    let decoded = decode_file(path)?;
+    let mono = downmix_to_mono(&decoded.samples, decoded.channels);
+    let samples = resample(&mono, decoded.sample_rate, TARGET_SAMPLE_RATE)?;
+
+    if state.active_stt_engine.lock().unwrap().is_none() {
+        if let Some(ref mid) = model_id {
+            tracing::info!("Lazy-loading STT engine for model: {}", mid);
+            crate::commands::models::load_stt_engine(app_handle, mid)?;
+        }
+    }
+
+    let chunks = split_chunks(
+        app_handle,
+        &samples,
+        FILE_CHUNK_DURATION_S,
+        FILE_SEARCH_WINDOW_S,
+        FILE_RMS_WINDOW_MS,
+    );
+    let total = chunks.len();
+    tracing::info!("Transcribing {}: {} samples, {} chunk(s)", path.display(), samples.len(), total);
+
+    let mut full_text = String::new();
+    let mut all_segments: Vec<Segment> = Vec::new();
+    let mut all_words: Vec<Word> = Vec::new();
+    let mut total_duration_ms = 0u64;
+    let mut result_language: Option<String> = None;
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        if cancel_flag.load(Ordering::Relaxed) {
+            tracing::info!("File transcription cancelled after {}/{} chunks", i, total);
+            anyhow::bail!("cancelled");
+        }
+
+        let _ = app_handle.emit("transcription-progress", serde_json::json!({
+            "chunk": i + 1,
+            "total": total,
+        }));
+
+        let chunk_audio = AudioBuffer {
+            samples: samples[chunk.start_sample..chunk.end_sample].to_vec(),
+            sample_rate: TARGET_SAMPLE_RATE,
+            channels: 1,
+        };
+
+        // The first chunk's language is kept for the rest of the file
+        let options = crate::commands::stt::transcribe_options(
+            app_handle,
+            DecodingProfile::File,
+            result_language.as_deref(),
+        );
+
+        let chunk_result = {
+            let engine_guard = state.active_stt_engine.lock().unwrap();
+            let engine = engine_guard.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("No STT model loaded"))?;
+            engine.transcribe(&chunk_audio, &options)?
+        };
+
+        all_segments.extend(timeline_segments(
+            &chunk_result,
+            samples_to_ms(chunk.start_sample),
+            samples_to_ms(chunk.end_sample),
+        ));
+        all_words.extend(timeline_words(&chunk_result, samples_to_ms(chunk.start_sample)));
+
+        crate::commands::stt::append_chunk_text(&mut full_text, &chunk_result.text);
+        total_duration_ms += chunk_result.duration_ms;
+        if result_language.is_none() {
+            result_language = chunk_result.language;
+        }
+    }
+
+    tracing::info!("File transcription complete: {} chars ({}ms)", full_text.len(), total_duration_ms);
+
+    Ok(TranscriptionResult {
+        text: full_text,
+        language: result_language,
+        duration_ms: total_duration_ms,
+        segments: Some(all_segments),
+        words: Some(all_words),
+        translated: false,
+    })
+}
+
+fn samples_to_ms(samples: usize) -> u64 {
+    samples as u64 * 1000 / TARGET_SAMPLE_RATE as u64
+}
@@ -1,14 +1,267 @@
+use tauri::{AppHandle, Emitter, Manager};
+use anyhow::Result;
+
+use crate::state::{AppState, AppStatus};
+use crate::audio::AudioPlayback;
+use crate::engine::TtsOptions;
+use crate::platform;
+
+const PLAYBACK_POLL_INTERVAL_MS: u64 = 100;
+
 #[tauri::command]
-pub fn speak_selected_text() -> Result<(), String> {
-    Err("TTS not yet implemented (coming in V2)".into())
+pub fn speak_selected_text(app_handle: AppHandle) -> Result<(), String> {
+    do_speak_selected_text(&app_handle).map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-pub fn speak_text(_text: String) -> Result<(), String> {
-    Err("TTS not yet implemented (coming in V2)".into())
+pub fn speak_text(app_handle: AppHandle, text: String) -> Result<(), String> {
+    do_speak_text(&app_handle, &text).map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-pub fn stop_speaking() -> Result<(), String> {
-    Err("TTS not yet implemented (coming in V2)".into())
+pub fn stop_speaking(app_handle: AppHandle) -> Result<(), String> {
+    do_stop_speaking(&app_handle).map_err(|e| e.to_string())
+}
+
+pub fn do_speak_selected_text(app_handle: &AppHandle) -> Result<()> {
+    let selector = platform::get_text_selector();
+    if !selector.is_supported() {
+        anyhow::bail!("Reading the selection is not supported on this platform");
+    }
+    let text = selector.get_selected_text()?
+        .filter(|t| !t.trim().is_empty())
+        .ok_or_else(|| anyhow::anyhow!("No text selected"))?;
+    do_speak_text(app_handle, &text)
+}
+
+/// Starts a TTS session: Synthesizing (including a lazy model load) → Playing → Idle.
+/// Returns once the session is started; synthesis and playback run on a worker thread.
+pub fn do_speak_text(app_handle: &AppHandle, text: &str) -> Result<()> {
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        anyhow::bail!("Nothing to read aloud");
+    }
+
+    let state = app_handle.state::<AppState>();
+
+    let engine_loaded = state.active_tts_engine.lock().unwrap().is_some();
+    let model_id = state.settings.lock().unwrap().tts.active_model_id.clone();
+
+    if !engine_loaded && model_id.is_none() {
+        anyhow::bail!("No TTS voice selected. Please select a voice in Settings.");
+    }
+
+    {
+        let mut status = state.status.lock().unwrap();
+        if *status != AppStatus::Idle {
+            anyhow::bail!("Cannot read aloud: app is not idle (current: {:?})", *status);
+        }
+        *status = AppStatus::Synthesizing;
+    }
+
+    cancel_tts_idle_timer(app_handle);
+
+    let session = {
+        let mut session = state.tts_session.lock().unwrap();
+        *session += 1;
+        *session
+    };
+
+    if let Some(window) = app_handle.get_webview_window("overlay") {
+        let _ = window.show();
+    }
+    let _ = app_handle.emit("overlay-mode", serde_json::json!({"mode": "tts"}));
+    let _ = app_handle.emit("recording-status", serde_json::json!({"status": "synthesizing"}));
+
+    tracing::info!("TTS started ({} chars, engine_loaded={})", text.len(), engine_loaded);
+
+    let handle = app_handle.clone();
+    let needs_load = !engine_loaded;
+    std::thread::spawn(move || {
+        if let Err(e) = run_tts_session(&handle, session, &text, needs_load, model_id) {
+            tracing::error!("TTS session failed: {}", e);
+        }
+        finish_tts_session(&handle, session);
+    });
+
+    Ok(())
+}
+
+fn run_tts_session(
+    app_handle: &AppHandle,
+    session: u64,
+    text: &str,
+    needs_load: bool,
+    model_id: Option<String>,
+) -> Result<()> {
+    let state = app_handle.state::<AppState>();
+
+    if needs_load {
+        if let Some(ref mid) = model_id {
+            tracing::info!("Lazy-loading TTS engine for model: {}", mid);
+            crate::commands::models::load_tts_engine(app_handle, mid)?;
+            tracing::info!("TTS engine loaded successfully");
+        }
+    }
+
+    if !is_current_session(app_handle, session) {
+        return Ok(());
+    }
+
+    let options = {
+        let settings = state.settings.lock().unwrap();
+        TtsOptions {
+            language: tts_language(settings.tts.active_model_id.as_deref()),
+            speed: settings.tts.speed,
+            voice_id: settings.tts.voice_id.clone(),
+        }
+    };
+
+    let audio = {
+        let engine_guard = state.active_tts_engine.lock().unwrap();
+        let engine = engine_guard.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No TTS model loaded"))?;
+        engine.synthesize(text, &options)?
+    };
+
+    if !is_current_session(app_handle, session) {
+        tracing::info!("TTS stopped during synthesis");
+        return Ok(());
+    }
+
+    {
+        let mut playback_guard = state.audio_playback.lock().unwrap();
+        if playback_guard.is_none() {
+            *playback_guard = Some(AudioPlayback::new(app_handle)?);
+        }
+        playback_guard.as_ref().unwrap().play(&audio)?;
+    }
+
+    {
+        let mut status = state.status.lock().unwrap();
+        if *status == AppStatus::Synthesizing {
+            *status = AppStatus::Playing;
+        }
+    }
+    let _ = app_handle.emit("recording-status", serde_json::json!({"status": "playing"}));
+
+    loop {
+        std::thread::sleep(std::time::Duration::from_millis(PLAYBACK_POLL_INTERVAL_MS));
+
+        if !is_current_session(app_handle, session) {
+            break;
+        }
+
+        let still_playing = state.audio_playback.lock().unwrap()
+            .as_ref()
+            .is_some_and(|p| p.is_playing() || p.is_paused());
+        if !still_playing {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns to Idle unless a newer session has already taken over (or stop_speaking did it).
+fn finish_tts_session(app_handle: &AppHandle, session: u64) {
+    if !is_current_session(app_handle, session) {
+        return;
+    }
+
+    let state = app_handle.state::<AppState>();
+    {
+        let mut status = state.status.lock().unwrap();
+        if *status != AppStatus::Synthesizing && *status != AppStatus::Playing {
+            return;
+        }
+        *status = AppStatus::Idle;
+    }
+
+    let _ = app_handle.emit("recording-status", serde_json::json!({"status": "idle"}));
+
+    reset_tts_idle_timer(app_handle);
+
+    if let Some(window) = app_handle.get_webview_window("overlay") {
+        let _ = window.hide();
+    }
+
+    tracing::info!("TTS session finished");
+}
+
+fn is_current_session(app_handle: &AppHandle, session: u64) -> bool {
+    let state = app_handle.state::<AppState>();
+    let current = *state.tts_session.lock().unwrap();
+    current == session
+}
+
+pub fn do_stop_speaking(app_handle: &AppHandle) -> Result<()> {
+    let state = app_handle.state::<AppState>();
+
+    // Invalidate the running session so its worker thread exits without touching status
+    *state.tts_session.lock().unwrap() += 1;
+
+    if let Some(playback) = state.audio_playback.lock().unwrap().as_ref() {
+        playback.stop()?;
+    }
+
+    {
+        let mut status = state.status.lock().unwrap();
+        if *status != AppStatus::Synthesizing && *status != AppStatus::Playing {
+            return Ok(());
+        }
+        *status = AppStatus::Idle;
+    }
+
+    let _ = app_handle.emit("recording-status", serde_json::json!({"status": "idle"}));
+
+    if let Some(window) = app_handle.get_webview_window("overlay") {
+        let _ = window.hide();
+    }
+
+    reset_tts_idle_timer(app_handle);
+
+    tracing::info!("TTS stopped");
+    Ok(())
+}
+
+/// First catalog language of the voice, used when the voice config has no espeak voice.
+fn tts_language(model_id: Option<&str>) -> String {
+    model_id
+        .and_then(|id| {
+            crate::hub::registry::load_catalog().ok()?
+                .into_iter()
+                .find(|m| m.id == id)
+                .and_then(|m| m.languages.into_iter().next())
+        })
+        .unwrap_or_else(|| "en".to_string())
+}
+
+pub(crate) fn reset_tts_idle_timer(app_handle: &AppHandle) {
+    cancel_tts_idle_timer(app_handle);
+
+    let state = app_handle.state::<AppState>();
+    let timeout_s = state.settings.lock().unwrap().tts.model_idle_timeout_s;
+
+    if let Some(seconds) = timeout_s {
+        let handle = app_handle.clone();
+        let task = tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_secs(seconds)).await;
+            let state = handle.state::<AppState>();
+            let mut engine_guard = state.active_tts_engine.lock().unwrap();
+            if engine_guard.is_some() {
+                *engine_guard = None;
+                tracing::info!("TTS engine unloaded after {}s idle timeout", seconds);
+            }
+        });
+        *state.tts_idle_timer_abort.lock().unwrap() = Some(task);
+    }
+}
+
+pub(crate) fn cancel_tts_idle_timer(app_handle: &AppHandle) {
+    let state = app_handle.state::<AppState>();
+    let abort_handle = state.tts_idle_timer_abort.lock().unwrap().take();
+    if let Some(handle) = abort_handle {
+        handle.abort();
+    }
 }
@@ -0,0 +1,341 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Emitter, Manager};
+use anyhow::Result;
+
+use crate::state::{AppState, AppStatus, CancelFlag, VoiceActivationSettings, VoiceSession};
+use crate::audio::AudioCapture;
+use crate::audio::processing::{resample, LiveSpeechDetector, VadConfig};
+use crate::engine::{AudioBuffer, DecodingProfile, Segment, TranscribeOptions, TranscriptionResult, Word};
+use crate::commands::stt::{
+    append_chunk_text, record_history, split_chunks, timeline_segments, timeline_words, transcribe_options,
+    DeviceWatch,
+};
+
+const VOICE_POLL_INTERVAL_MS: u64 = 50;
+/// Detector resolution: each block of this length is one level update.
+const VOICE_BLOCK_MS: f32 = 30.0;
+const UTTERANCE_CHUNK_DURATION_S: f32 = 20.0;
+const UTTERANCE_SEARCH_WINDOW_S: f32 = 2.0;
+const UTTERANCE_RMS_WINDOW_MS: f32 = 100.0;
+const TARGET_SAMPLE_RATE: u32 = 16000;
+
+#[tauri::command]
+pub fn arm_voice_activation(app_handle: AppHandle) -> Result<(), String> {
+    let translate = app_handle.state::<AppState>().settings.lock().unwrap().stt.translate_to_english;
+    do_arm_voice_activation(&app_handle, translate).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn disarm_voice_activation(app_handle: AppHandle) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || do_disarm_voice_activation(&app_handle))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+pub fn is_armed(app_handle: &AppHandle) -> bool {
+    app_handle.state::<AppState>().voice_session.lock().unwrap().is_some()
+}
+
+/// Opens the microphone and starts listening for utterances. Each one is transcribed
+/// and injected when it ends, until [`do_disarm_voice_activation`].
+pub fn do_arm_voice_activation(app_handle: &AppHandle, translate: bool) -> Result<()> {
+    let state = app_handle.state::<AppState>();
+
+    let engine_loaded = state.active_stt_engine.lock().unwrap().is_some();
+    let model_id = state.settings.lock().unwrap().stt.active_model_id.clone();
+
+    if !engine_loaded && model_id.is_none() {
+        anyhow::bail!("No STT model selected. Please select a model in Settings.");
+    }
+
+    if translate {
+        if let Some(ref mid) = model_id {
+            crate::commands::stt::ensure_translation_supported(mid)?;
+        }
+    }
+
+    crate::commands::stt::cancel_idle_timer(app_handle);
+
+    let capture = {
+        let mut status = state.status.lock().unwrap();
+        if *status != AppStatus::Idle {
+            anyhow::bail!("Cannot arm voice activation: app is not idle (current: {:?})", *status);
+        }
+
+        let capture = crate::commands::stt::open_capture(app_handle)?;
+
+        *status = if engine_loaded { AppStatus::Armed } else { AppStatus::Loading };
+        capture
+    };
+
+    let monitor = capture.level_monitor();
+    let handle = app_handle.clone();
+    std::thread::spawn(move || {
+        while monitor.is_active() {
+            let level = (monitor.current_level() * 8.0).sqrt().min(1.0);
+            let _ = handle.emit("audio-level", serde_json::json!({"level": level}));
+            std::thread::sleep(std::time::Duration::from_millis(VOICE_POLL_INTERVAL_MS));
+        }
+    });
+
+    // Held until the session is stored: a listener that exits straight away blocks in
+    // finish_listening until then, so it clears this session and hides the overlay last
+    let mut session = state.voice_session.lock().unwrap();
+
+    if let Some(window) = app_handle.get_webview_window("overlay") {
+        let _ = window.show();
+    }
+    let status = if engine_loaded { "armed" } else { "loading" };
+    let _ = app_handle.emit("recording-status", serde_json::json!({"status": status}));
+    let _ = app_handle.emit("overlay-mode", serde_json::json!({"mode": "stt", "translate": translate}));
+
+    let stop: CancelFlag = Arc::new(AtomicBool::new(false));
+    let handle = app_handle.clone();
+    let thread_stop = stop.clone();
+    let model_to_load = if engine_loaded { None } else { model_id };
+    let thread = std::thread::spawn(move || {
+        voice_listen_loop(handle, capture, thread_stop, translate, model_to_load);
+    });
+    *session = Some(VoiceSession { stop, thread });
+    drop(session);
+
+    tracing::info!("Voice activation armed (engine_loaded={}, translate={})", engine_loaded, translate);
+    Ok(())
+}
+
+/// Stops listening. Blocks until an utterance in progress has been transcribed.
+pub fn do_disarm_voice_activation(app_handle: &AppHandle) -> Result<()> {
+    let state = app_handle.state::<AppState>();
+    let session = state.voice_session.lock().unwrap().take()
+        .ok_or_else(|| anyhow::anyhow!("Voice activation is not armed"))?;
+    session.stop.store(true, Ordering::Relaxed);
+    let _ = session.thread.join();
+    tracing::info!("Voice activation disarmed");
+    Ok(())
+}
+
+fn voice_listen_loop(
+    app_handle: AppHandle,
+    mut capture: AudioCapture,
+    stop: CancelFlag,
+    translate: bool,
+    model_to_load: Option<String>,
+) {
+    if let Some(mid) = model_to_load {
+        tracing::info!("Lazy-loading STT engine for model: {}", mid);
+        if let Err(e) = crate::commands::models::load_stt_engine(&app_handle, &mid) {
+            tracing::error!("Failed to lazy-load STT engine: {}", e);
+            finish_listening(&app_handle, &mut capture);
+            return;
+        }
+        set_status(&app_handle, AppStatus::Armed);
+    }
+
+    {
+        let state = app_handle.state::<AppState>();
+        let engine_guard = state.active_stt_engine.lock().unwrap();
+        if let Some(engine) = engine_guard.as_ref() {
+            if let Err(e) = engine.warm_up() {
+                tracing::error!("Engine warm_up failed: {}", e);
+            }
+        }
+    }
+
+    let settings: VoiceActivationSettings =
+        app_handle.state::<AppState>().settings.lock().unwrap().stt.voice_activation.clone();
+    let drain = capture.streaming_drain();
+    let device_rate = drain.device_sample_rate();
+    let ms_to_samples = |ms: f32| (ms * device_rate as f32 / 1000.0) as usize;
+    let block_len = ms_to_samples(VOICE_BLOCK_MS).max(1);
+    let pre_roll_len = ms_to_samples(settings.pre_roll_ms as f32);
+    let max_utterance_len = ms_to_samples(settings.max_utterance_s * 1000.0);
+
+    let mut detector = LiveSpeechDetector::new(VadConfig {
+        threshold_db: settings.sensitivity.threshold_db(),
+        ..VadConfig::default()
+    });
+    let mut pending: Vec<f32> = Vec::new();
+    let mut pre_roll: VecDeque<f32> = VecDeque::with_capacity(pre_roll_len + block_len);
+    let mut utterance: Option<Vec<f32>> = None;
+
+    // Audio keeps buffering in the capture while an utterance is transcribed, and is
+    // picked up on the next pass
+    let mut device_watch = DeviceWatch::new();
+
+    while !stop.load(Ordering::Relaxed) {
+        std::thread::sleep(std::time::Duration::from_millis(VOICE_POLL_INTERVAL_MS));
+        if !device_watch.check(&app_handle, &mut capture) {
+            break;
+        }
+        pending.extend(drain.drain().1);
+
+        let mut consumed = 0;
+        let mut ended = false;
+        while !ended && pending.len() - consumed >= block_len {
+            let block = &pending[consumed..consumed + block_len];
+            consumed += block_len;
+            let level = (block.iter().map(|s| s * s).sum::<f32>() / block_len as f32).sqrt();
+            let speaking = detector.update(level, VOICE_BLOCK_MS);
+
+            match utterance.as_mut() {
+                Some(audio) => {
+                    audio.extend_from_slice(block);
+                    ended = (!speaking && detector.silence_ms() >= settings.end_silence_ms as f32)
+                        || audio.len() >= max_utterance_len;
+                }
+                None => {
+                    pre_roll.extend(block.iter().copied());
+                    let excess = pre_roll.len().saturating_sub(pre_roll_len.max(block_len));
+                    pre_roll.drain(..excess);
+                    if speaking {
+                        utterance = Some(pre_roll.drain(..).collect());
+                        set_status(&app_handle, AppStatus::Recording);
+                    }
+                }
+            }
+        }
+        pending.drain(..consumed);
+
+        if ended {
+            if let Some(audio) = utterance.take() {
+                if let Err(e) = finish_utterance(&app_handle, &audio, device_rate, translate) {
+                    tracing::error!("Voice-activated transcription failed: {}", e);
+                }
+            }
+            detector.end_utterance();
+            set_status(&app_handle, AppStatus::Armed);
+        }
+    }
+
+    // Disarmed, or the device is gone, mid-utterance: keep what was said so far
+    if let Some(mut audio) = utterance.take() {
+        audio.extend(pending);
+        audio.extend(drain.drain().1);
+        if let Err(e) = finish_utterance(&app_handle, &audio, device_rate, translate) {
+            tracing::error!("Voice-activated transcription failed: {}", e);
+        }
+    }
+
+    finish_listening(&app_handle, &mut capture);
+}
+
+/// Transcribes one utterance of device-rate audio, saves it to history and injects it.
+fn finish_utterance(app_handle: &AppHandle, raw: &[f32], device_rate: u32, translate: bool) -> Result<()> {
+    set_status(app_handle, AppStatus::Transcribing);
+    let state = app_handle.state::<AppState>();
+
+    let samples = if device_rate != TARGET_SAMPLE_RATE {
+        resample(raw, device_rate, TARGET_SAMPLE_RATE)?
+    } else {
+        raw.to_vec()
+    };
+
+    let chunks = split_chunks(
+        app_handle,
+        &samples,
+        UTTERANCE_CHUNK_DURATION_S,
+        UTTERANCE_SEARCH_WINDOW_S,
+        UTTERANCE_RMS_WINDOW_MS,
+    );
+
+    let mut full_text = String::new();
+    let mut all_segments: Vec<Segment> = Vec::new();
+    let mut all_words: Vec<Word> = Vec::new();
+    let mut total_duration_ms = 0u64;
+    let mut result_language: Option<String> = None;
+
+    for chunk in &chunks {
+        let options = TranscribeOptions {
+            translate,
+            ..transcribe_options(app_handle, DecodingProfile::Live, result_language.as_deref())
+        };
+        let chunk_audio = AudioBuffer {
+            samples: samples[chunk.start_sample..chunk.end_sample].to_vec(),
+            sample_rate: TARGET_SAMPLE_RATE,
+            channels: 1,
+        };
+
+        let chunk_result = {
+            let engine_guard = state.active_stt_engine.lock().unwrap();
+            let engine = engine_guard.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("No STT model loaded"))?;
+            engine.transcribe(&chunk_audio, &options)?
+        };
+
+        all_segments.extend(timeline_segments(
+            &chunk_result,
+            samples_to_ms(chunk.start_sample),
+            samples_to_ms(chunk.end_sample),
+        ));
+        all_words.extend(timeline_words(&chunk_result, samples_to_ms(chunk.start_sample)));
+        append_chunk_text(&mut full_text, &chunk_result.text);
+        total_duration_ms += chunk_result.duration_ms;
+        if result_language.is_none() {
+            result_language = chunk_result.language;
+        }
+    }
+
+    if full_text.trim().is_empty() {
+        tracing::info!("Utterance of {} samples produced no text", samples.len());
+        return Ok(());
+    }
+
+    let result = TranscriptionResult {
+        text: full_text,
+        language: result_language,
+        duration_ms: total_duration_ms,
+        segments: if all_segments.is_empty() { None } else { Some(all_segments) },
+        words: if all_words.is_empty() { None } else { Some(all_words) },
+        translated: translate,
+    };
+
+    tracing::info!("Utterance transcribed: '{}' ({}ms)", result.text, result.duration_ms);
+
+    record_history(app_handle, &result);
+    crate::commands::stt::inject_transcript(app_handle, &result.text)?;
+
+    let _ = app_handle.emit("transcription-complete", serde_json::json!({
+        "text": result.text,
+        "duration_ms": result.duration_ms,
+    }));
+    Ok(())
+}
+
+/// Closes the microphone and returns to idle once the listener is done.
+fn finish_listening(app_handle: &AppHandle, capture: &mut AudioCapture) {
+    let _ = capture.stop();
+    let state = app_handle.state::<AppState>();
+
+    {
+        let engine_guard = state.active_stt_engine.lock().unwrap();
+        if let Some(engine) = engine_guard.as_ref() {
+            if let Err(e) = engine.cool_down() {
+                tracing::error!("Engine cool_down failed: {}", e);
+            }
+        }
+    }
+
+    // Also reached without a disarm (engine failed to load, device gone): drop the
+    // session before going idle so the next shortcut press arms again
+    state.voice_session.lock().unwrap().take();
+    set_status(app_handle, AppStatus::Idle);
+    if let Some(window) = app_handle.get_webview_window("overlay") {
+        let _ = window.hide();
+    }
+    crate::commands::stt::reset_idle_timer(app_handle);
+}
+
+fn set_status(app_handle: &AppHandle, status: AppStatus) {
+    let state = app_handle.state::<AppState>();
+    *state.status.lock().unwrap() = status.clone();
+    if let Ok(serde_json::Value::String(name)) = serde_json::to_value(&status) {
+        let _ = app_handle.emit("recording-status", serde_json::json!({"status": name}));
+    }
+}
+
+fn samples_to_ms(samples: usize) -> u64 {
+    samples as u64 * 1000 / TARGET_SAMPLE_RATE as u64
+}
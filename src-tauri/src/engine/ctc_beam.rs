@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use super::hotwords::HotwordTrie;
+use super::ngram::NgramLm;
+use super::onnx_stt::TimedToken;
+
+const WORD_BOUNDARY: char = '\u{2581}';
+
+/// Word-level LM state of a prefix: the completed words' context and score, plus the
+/// SentencePiece pieces of the word still being spelled.
+#[derive(Clone, Default)]
+struct LmState {
+    context: Vec<u32>,
+    partial: String,
+    score: f32,
+    words: usize,
+}
+
+impl LmState {
+    fn complete_word(&mut self, lm: &NgramLm) {
+        let word = std::mem::take(&mut self.partial);
+        if let Some((score, context)) = lm.score_word(&self.context, &word) {
+            self.score += score;
+            self.context = context;
+            self.words += 1;
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Beam {
+    tokens: Vec<TimedToken>,
+    /// Log probability of the prefix ending in blank / in its last token.
+    p_blank: f32,
+    p_non_blank: f32,
+    lm: LmState,
+    /// Hotword trie node and the biasing score earned so far.
+    hotword: usize,
+    bias: f32,
+}
+
+impl Beam {
+    fn acoustic(&self) -> f32 {
+        log_add(self.p_blank, self.p_non_blank)
+    }
+}
+
+/// CTC prefix beam search with optional word-level shallow fusion:
+/// `score = log P_ctc + lm_weight * log P_lm + word_insertion_bonus * words`, plus the
+/// hotword bias when a trie is given.
+pub struct CtcBeamSearch<'a> {
+    pub pieces: &'a [String],
+    pub blank_id: usize,
+    pub beam_width: usize,
+    pub lm: Option<&'a NgramLm>,
+    pub lm_weight: f32,
+    pub word_insertion_bonus: f32,
+    pub hotwords: Option<&'a HotwordTrie>,
+}
+
+impl CtcBeamSearch<'_> {
+    /// `logits` is `[time_steps, vocab_size]` row-major, raw or log-softmaxed.
+    pub fn decode(&self, logits: &[f32], time_steps: usize, vocab_size: usize) -> Vec<TimedToken> {
+        let beam_width = self.beam_width.max(1);
+        let initial = Beam {
+            tokens: Vec::new(),
+            p_blank: 0.0,
+            p_non_blank: f32::NEG_INFINITY,
+            lm: LmState {
+                context: self.lm.map(|lm| lm.start_context()).unwrap_or_default(),
+                ..LmState::default()
+            },
+            hotword: self.hotwords.map(|h| h.root()).unwrap_or_default(),
+            bias: 0.0,
+        };
+        let mut beams: HashMap<Vec<usize>, Beam> = HashMap::from([(Vec::new(), initial)]);
+
+        for t in 0..time_steps {
+            let frame_end = (t + 1) * vocab_size;
+            if frame_end > logits.len() { break; }
+            let frame = log_softmax(&logits[t * vocab_size..frame_end]);
+            let candidates = top_k(&frame, beam_width, self.blank_id);
+
+            let mut next: HashMap<Vec<usize>, Beam> = HashMap::new();
+
+            for (key, beam) in &beams {
+                let total = beam.acoustic();
+
+                let stay = next.entry(key.clone()).or_insert_with(|| reset(beam));
+                stay.p_blank = log_add(stay.p_blank, total + frame[self.blank_id]);
+
+                let mut beam_candidates = candidates.clone();
+                if let Some(h) = self.hotwords {
+                    beam_candidates.extend(h.continuations(beam.hotword).into_iter()
+                        .filter(|c| *c != self.blank_id && *c < vocab_size && !candidates.contains(c)));
+                }
+
+                for &c in &beam_candidates {
+                    let lp = frame[c];
+                    let from = if key.last() == Some(&c) {
+                        // Repeated token without a blank in between collapses into the same prefix
+                        let stay = next.entry(key.clone()).or_insert_with(|| reset(beam));
+                        stay.p_non_blank = log_add(stay.p_non_blank, beam.p_non_blank + lp);
+                        if let Some(last) = stay.tokens.last_mut() {
+                            last.end_frame = t + 1;
+                        }
+                        beam.p_blank
+                    } else {
+                        total
+                    };
+
+                    let mut extended_key = key.clone();
+                    extended_key.push(c);
+                    let extended = next.entry(extended_key)
+                        .or_insert_with(|| self.extend(beam, c, t, lp));
+                    extended.p_non_blank = log_add(extended.p_non_blank, from + lp);
+                }
+            }
+
+            let mut ranked: Vec<(Vec<usize>, Beam)> = next.into_iter().collect();
+            ranked.sort_by(|a, b| self.rank(&b.1).total_cmp(&self.rank(&a.1)));
+            ranked.truncate(beam_width);
+            beams = ranked.into_iter().collect();
+        }
+
+        beams.into_values()
+            .map(|mut beam| {
+                if let Some(lm) = self.lm {
+                    beam.lm.complete_word(lm);
+                    beam.lm.score += lm.score_end(&beam.lm.context);
+                }
+                if let Some(h) = self.hotwords {
+                    beam.bias += h.unfinished(beam.hotword);
+                }
+                let score = self.rank(&beam);
+                (score, beam)
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, beam)| beam.tokens)
+            .unwrap_or_default()
+    }
+
+    fn extend(&self, beam: &Beam, token: usize, t: usize, log_prob: f32) -> Beam {
+        let mut extended = reset(beam);
+        extended.tokens.push(TimedToken {
+            id: token,
+            start_frame: t,
+            end_frame: t + 1,
+            prob: log_prob.exp(),
+        });
+
+        if let (Some(lm), Some(piece)) = (self.lm, self.pieces.get(token)) {
+            if piece.starts_with(WORD_BOUNDARY) {
+                if !extended.lm.partial.is_empty() {
+                    extended.lm.complete_word(lm);
+                }
+                extended.lm.partial = piece.trim_start_matches(WORD_BOUNDARY).to_string();
+            } else {
+                extended.lm.partial.push_str(piece);
+            }
+        }
+
+        if let Some(h) = self.hotwords {
+            let (node, delta) = h.advance(extended.hotword, token);
+            extended.hotword = node;
+            extended.bias += delta;
+        }
+        extended
+    }
+
+    fn rank(&self, beam: &Beam) -> f32 {
+        if self.lm.is_none() {
+            return beam.acoustic() + beam.bias;
+        }
+        beam.acoustic()
+            + beam.bias
+            + self.lm_weight * beam.lm.score
+            + self.word_insertion_bonus * beam.lm.words as f32
+    }
+}
+
+/// Same prefix, with probabilities cleared for accumulation in the next frame.
+fn reset(beam: &Beam) -> Beam {
+    Beam {
+        tokens: beam.tokens.clone(),
+        p_blank: f32::NEG_INFINITY,
+        p_non_blank: f32::NEG_INFINITY,
+        lm: beam.lm.clone(),
+        hotword: beam.hotword,
+        bias: beam.bias,
+    }
+}
+
+pub(super) fn log_add(a: f32, b: f32) -> f32 {
+    if a == f32::NEG_INFINITY {
+        return b;
+    }
+    if b == f32::NEG_INFINITY {
+        return a;
+    }
+    let max = a.max(b);
+    max + ((a - max).exp() + (b - max).exp()).ln()
+}
+
+pub(super) fn log_softmax(logits: &[f32]) -> Vec<f32> {
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let log_sum = logits.iter().map(|&l| (l - max).exp()).sum::<f32>().ln() + max;
+    logits.iter().map(|&l| l - log_sum).collect()
+}
+
+/// The `k` most likely non-blank tokens of a frame.
+pub(super) fn top_k(frame: &[f32], k: usize, blank_id: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..frame.len()).filter(|&i| i != blank_id).collect();
+    let k = k.min(indices.len());
+    if k == 0 {
+        return indices;
+    }
+    indices.select_nth_unstable_by(k - 1, |&a, &b| frame[b].total_cmp(&frame[a]));
+    indices.truncate(k);
+    indices
+}
@@ -0,0 +1,239 @@
+use serde::{Serialize, Deserialize};
+
+/// Per-model decoder settings, stored in `SttSettings.decoding` keyed by model id.
+/// Each engine reads the part that applies to it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecodingConfig {
+    #[serde(default)]
+    pub ctc: CtcDecodingConfig,
+    #[serde(default)]
+    pub tdt: TdtDecodingConfig,
+    /// Whisper settings for live dictation.
+    #[serde(default = "WhisperDecodingConfig::live")]
+    pub whisper: WhisperDecodingConfig,
+    /// Whisper settings for `transcribe_file`, where latency matters less.
+    #[serde(default = "WhisperDecodingConfig::file")]
+    pub whisper_file: WhisperDecodingConfig,
+}
+
+impl DecodingConfig {
+    pub fn whisper_for(&self, profile: DecodingProfile) -> &WhisperDecodingConfig {
+        match profile {
+            DecodingProfile::Live => &self.whisper,
+            DecodingProfile::File => &self.whisper_file,
+        }
+    }
+}
+
+impl Default for DecodingConfig {
+    fn default() -> Self {
+        Self {
+            ctc: CtcDecodingConfig::default(),
+            tdt: TdtDecodingConfig::default(),
+            whisper: WhisperDecodingConfig::live(),
+            whisper_file: WhisperDecodingConfig::file(),
+        }
+    }
+}
+
+/// What a transcription is for, selecting between speed- and quality-oriented settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodingProfile {
+    Live,
+    File,
+}
+
+impl Default for DecodingProfile {
+    fn default() -> Self {
+        Self::Live
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CtcStrategy {
+    Greedy,
+    BeamSearch,
+}
+
+impl Default for CtcStrategy {
+    fn default() -> Self {
+        Self::Greedy
+    }
+}
+
+/// Parakeet CTC decoding. The LM settings only apply to beam search, and
+/// `lm_weight`/`word_insertion_bonus` only when `lm_path` is set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CtcDecodingConfig {
+    #[serde(default)]
+    pub strategy: CtcStrategy,
+    #[serde(default = "default_beam_width")]
+    pub beam_width: usize,
+    /// ARPA n-gram language model used for shallow fusion.
+    #[serde(default)]
+    pub lm_path: Option<String>,
+    #[serde(default = "default_lm_weight")]
+    pub lm_weight: f32,
+    /// Added per completed word, offsetting the LM's bias towards short outputs.
+    #[serde(default = "default_word_insertion_bonus")]
+    pub word_insertion_bonus: f32,
+}
+
+fn default_beam_width() -> usize {
+    8
+}
+
+fn default_lm_weight() -> f32 {
+    0.5
+}
+
+fn default_word_insertion_bonus() -> f32 {
+    1.5
+}
+
+impl Default for CtcDecodingConfig {
+    fn default() -> Self {
+        Self {
+            strategy: CtcStrategy::default(),
+            beam_width: default_beam_width(),
+            lm_path: None,
+            lm_weight: default_lm_weight(),
+            word_insertion_bonus: default_word_insertion_bonus(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TdtStrategy {
+    Greedy,
+    BeamSearch,
+}
+
+impl Default for TdtStrategy {
+    fn default() -> Self {
+        Self::Greedy
+    }
+}
+
+/// Parakeet TDT decoding. `max_symbols_per_step` caps the tokens emitted on one encoder
+/// frame (duration 0) for both strategies.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TdtDecodingConfig {
+    #[serde(default)]
+    pub strategy: TdtStrategy,
+    #[serde(default = "default_tdt_beam_width")]
+    pub beam_width: usize,
+    #[serde(default = "default_max_symbols_per_step")]
+    pub max_symbols_per_step: usize,
+}
+
+fn default_tdt_beam_width() -> usize {
+    4
+}
+
+fn default_max_symbols_per_step() -> usize {
+    10
+}
+
+impl Default for TdtDecodingConfig {
+    fn default() -> Self {
+        Self {
+            strategy: TdtStrategy::default(),
+            beam_width: default_tdt_beam_width(),
+            max_symbols_per_step: default_max_symbols_per_step(),
+        }
+    }
+}
+
+/// whisper.cpp `FullParams` decoding settings. Thresholds use whisper.cpp's defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WhisperDecodingConfig {
+    /// 1 decodes greedily; larger values use beam search.
+    #[serde(default = "default_whisper_beam_size")]
+    pub beam_size: usize,
+    /// Candidates sampled per temperature when decoding greedily with fallback.
+    #[serde(default = "default_best_of")]
+    pub best_of: usize,
+    #[serde(default)]
+    pub temperature: f32,
+    /// Step by which the temperature is raised when a window fails the thresholds
+    /// below. 0 disables fallback.
+    #[serde(default = "default_temperature_inc")]
+    pub temperature_inc: f32,
+    #[serde(default = "default_entropy_thold")]
+    pub entropy_thold: f32,
+    #[serde(default = "default_logprob_thold")]
+    pub logprob_thold: f32,
+    #[serde(default = "default_no_speech_thold")]
+    pub no_speech_thold: f32,
+    #[serde(default = "default_suppress_blank")]
+    pub suppress_blank: bool,
+    #[serde(default)]
+    pub suppress_non_speech_tokens: bool,
+    /// 0 uses whisper.cpp's default (up to 4 threads).
+    #[serde(default)]
+    pub n_threads: usize,
+}
+
+fn default_whisper_beam_size() -> usize {
+    1
+}
+
+fn default_best_of() -> usize {
+    1
+}
+
+fn default_temperature_inc() -> f32 {
+    0.2
+}
+
+fn default_entropy_thold() -> f32 {
+    2.4
+}
+
+fn default_logprob_thold() -> f32 {
+    -1.0
+}
+
+fn default_no_speech_thold() -> f32 {
+    0.6
+}
+
+fn default_suppress_blank() -> bool {
+    true
+}
+
+impl WhisperDecodingConfig {
+    /// Greedy decoding, for the lowest latency while dictating.
+    pub fn live() -> Self {
+        Self {
+            beam_size: default_whisper_beam_size(),
+            best_of: default_best_of(),
+            temperature: 0.0,
+            temperature_inc: default_temperature_inc(),
+            entropy_thold: default_entropy_thold(),
+            logprob_thold: default_logprob_thold(),
+            no_speech_thold: default_no_speech_thold(),
+            suppress_blank: default_suppress_blank(),
+            suppress_non_speech_tokens: false,
+            n_threads: 0,
+        }
+    }
+
+    /// Beam search with 5 beams, as in the reference Whisper implementation.
+    pub fn file() -> Self {
+        Self {
+            beam_size: 5,
+            best_of: 5,
+            ..Self::live()
+        }
+    }
+}
+
+impl Default for WhisperDecodingConfig {
+    fn default() -> Self {
+        Self::live()
+    }
+}
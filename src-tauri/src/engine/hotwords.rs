@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use super::ctc_beam::log_softmax;
+
+const WORD_BOUNDARY: char = '\u{2581}';
+const ROOT: usize = 0;
+
+struct Node {
+    children: HashMap<usize, usize>,
+    /// Boosted tokens since the last completed hotword on this path. Taken back if
+    /// the match breaks off here.
+    pending: usize,
+    terminal: bool,
+}
+
+/// Contextual biasing for the Parakeet decoders. Hotwords are split into vocabulary
+/// pieces and stored in a trie; every token that extends a match earns `boost`, and the
+/// boost of a partial match that fails is taken back, so only complete hotwords keep it.
+pub struct HotwordTrie {
+    nodes: Vec<Node>,
+    boost: f32,
+}
+
+impl HotwordTrie {
+    /// Returns `None` when no phrase could be spelled with `pieces`.
+    pub fn build(phrases: &[String], pieces: &[String], boost: f32) -> Option<Self> {
+        let piece_ids: HashMap<&str, usize> = pieces.iter()
+            .enumerate()
+            .map(|(i, p)| (p.as_str(), i))
+            .collect();
+        let max_piece_chars = pieces.iter().map(|p| p.chars().count()).max().unwrap_or(0);
+
+        let mut trie = Self {
+            nodes: vec![Node { children: HashMap::new(), pending: 0, terminal: false }],
+            boost,
+        };
+
+        for phrase in phrases {
+            match tokenize(phrase, &piece_ids, max_piece_chars) {
+                Some(tokens) if !tokens.is_empty() => trie.insert(&tokens),
+                _ => tracing::warn!("Hotword {:?} cannot be spelled with the model vocabulary", phrase),
+            }
+        }
+
+        if trie.nodes[ROOT].children.is_empty() {
+            return None;
+        }
+        tracing::info!("Hotword trie: {} phrase(s), {} node(s)", phrases.len(), trie.nodes.len());
+        Some(trie)
+    }
+
+    fn insert(&mut self, tokens: &[usize]) {
+        let mut node = ROOT;
+        for &token in tokens {
+            node = match self.nodes[node].children.get(&token) {
+                Some(&next) => next,
+                None => {
+                    let pending = if self.nodes[node].terminal { 1 } else { self.nodes[node].pending + 1 };
+                    self.nodes.push(Node { children: HashMap::new(), pending, terminal: false });
+                    let next = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(token, next);
+                    next
+                }
+            };
+        }
+        self.nodes[node].terminal = true;
+        self.recompute_pending(node, 0);
+    }
+
+    /// After marking `node` terminal, its descendants only risk the boost earned below it.
+    fn recompute_pending(&mut self, node: usize, pending: usize) {
+        let children: Vec<usize> = self.nodes[node].children.values().copied().collect();
+        for child in children {
+            let child_pending = pending + 1;
+            self.nodes[child].pending = child_pending;
+            if !self.nodes[child].terminal {
+                self.recompute_pending(child, child_pending);
+            }
+        }
+    }
+
+    pub fn root(&self) -> usize {
+        ROOT
+    }
+
+    /// Tokens worth considering after `node`: those continuing the current match and
+    /// those starting a new hotword.
+    pub fn continuations(&self, node: usize) -> Vec<usize> {
+        let mut tokens: Vec<usize> = self.nodes[node].children.keys()
+            .chain(self.nodes[ROOT].children.keys())
+            .copied()
+            .collect();
+        tokens.sort_unstable();
+        tokens.dedup();
+        tokens
+    }
+
+    /// Node after emitting `token` from `node`, and the score change.
+    pub fn advance(&self, node: usize, token: usize) -> (usize, f32) {
+        if let Some(&next) = self.nodes[node].children.get(&token) {
+            return (self.settle(next), self.boost);
+        }
+        let refund = self.unfinished(node);
+        match self.nodes[ROOT].children.get(&token) {
+            Some(&next) if node != ROOT => (self.settle(next), refund + self.boost),
+            _ => (ROOT, refund),
+        }
+    }
+
+    /// Score to take back for a match still open when decoding ends.
+    pub fn unfinished(&self, node: usize) -> f32 {
+        if self.nodes[node].terminal {
+            return 0.0;
+        }
+        -(self.nodes[node].pending as f32) * self.boost
+    }
+
+    /// Completed hotwords with nothing longer to match go back to the root.
+    fn settle(&self, node: usize) -> usize {
+        if self.nodes[node].terminal && self.nodes[node].children.is_empty() { ROOT } else { node }
+    }
+
+    /// Greedy pick over `logits` with hotword continuations of `node` boosted. Returns
+    /// the token and its unboosted probability.
+    pub fn biased_argmax(&self, logits: &[f32], node: usize) -> Option<(usize, f32)> {
+        let mut scores = log_softmax(logits);
+        let log_probs = scores.clone();
+        for token in self.continuations(node) {
+            if let Some(score) = scores.get_mut(token) {
+                *score += self.boost;
+            }
+        }
+        scores.iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(i, _)| (i, log_probs[i].exp()))
+    }
+}
+
+/// Greedy longest-match SentencePiece spelling of `phrase`, one `▁`-prefixed word at a time.
+fn tokenize(phrase: &str, piece_ids: &HashMap<&str, usize>, max_piece_chars: usize) -> Option<Vec<usize>> {
+    let mut tokens = Vec::new();
+    for word in phrase.split_whitespace() {
+        let chars: Vec<char> = std::iter::once(WORD_BOUNDARY).chain(word.chars()).collect();
+        let mut pos = 0;
+        while pos < chars.len() {
+            let longest = (1..=max_piece_chars.min(chars.len() - pos)).rev().find_map(|len| {
+                let piece: String = chars[pos..pos + len].iter().collect();
+                piece_ids.get(piece.as_str()).map(|&id| (id, len))
+            });
+            let (id, len) = longest?;
+            tokens.push(id);
+            pos += len;
+        }
+    }
+    Some(tokens)
+}
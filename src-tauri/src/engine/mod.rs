@@ -1,11 +1,18 @@
 pub mod whisper_stt;
 pub mod onnx_stt;
 pub mod onnx_tts;
+pub mod decoding;
+mod ctc_beam;
+mod tdt_beam;
+mod hotwords;
+mod ngram;
 
 use std::path::Path;
 use anyhow::Result;
 use serde::{Serialize, Deserialize};
 
+pub use decoding::{DecodingConfig, DecodingProfile};
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ModelCapability {
@@ -43,6 +50,11 @@ pub struct TranscriptionResult {
     pub language: Option<String>,
     pub duration_ms: u64,
     pub segments: Option<Vec<Segment>>,
+    #[serde(default)]
+    pub words: Option<Vec<Word>>,
+    /// The text is an English translation of speech in `language`.
+    #[serde(default)]
+    pub translated: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,6 +64,31 @@ pub struct Segment {
     pub text: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Word {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub text: String,
+    /// Mean probability of the word's tokens, 0.0–1.0.
+    pub confidence: f32,
+}
+
+/// Per-call transcription options, resolved from settings for the active model.
+#[derive(Debug, Clone, Default)]
+pub struct TranscribeOptions {
+    pub language: Option<String>,
+    pub decoding: DecodingConfig,
+    pub profile: DecodingProfile,
+    /// With no `language`, restricts detection to these languages (Whisper only).
+    pub allowed_languages: Vec<String>,
+    /// Translate the speech to English instead of transcribing it. Only Whisper supports it.
+    pub translate: bool,
+    /// Phrases to favour: a prompt for Whisper, decoder biasing for Parakeet.
+    pub hotwords: Vec<String>,
+    /// Parakeet log-probability bonus per hotword token.
+    pub hotword_boost: f32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TtsOptions {
     pub language: String,
@@ -67,7 +104,7 @@ pub trait Engine: Send + Sync {
 }
 
 pub trait SttEngine: Engine {
-    fn transcribe(&self, audio: &AudioBuffer, language: Option<&str>) -> Result<TranscriptionResult>;
+    fn transcribe(&self, audio: &AudioBuffer, options: &TranscribeOptions) -> Result<TranscriptionResult>;
     fn warm_up(&self) -> Result<()> { Ok(()) }
     fn cool_down(&self) -> Result<()> { Ok(()) }
 }
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use anyhow::{Result, Context};
+
+const SENTENCE_START: &str = "<s>";
+const SENTENCE_END: &str = "</s>";
+const UNKNOWN: &str = "<unk>";
+/// Used for out-of-vocabulary words when the model has no `<unk>` entry.
+const OOV_LOG10_PROB: f32 = -10.0;
+
+/// Back-off n-gram language model loaded from an ARPA file. The whole model is kept in
+/// memory, so it is meant for domain LMs rather than web-scale ones.
+///
+/// Words are matched lowercased and stripped of surrounding punctuation, since Parakeet
+/// outputs cased, punctuated text while most ARPA models are trained on normalized text.
+pub struct NgramLm {
+    order: usize,
+    words: HashMap<String, u32>,
+    /// n-gram word ids → (log10 probability, log10 back-off weight)
+    ngrams: HashMap<Vec<u32>, (f32, f32)>,
+    start_id: Option<u32>,
+    end_id: Option<u32>,
+    unk_id: Option<u32>,
+}
+
+impl NgramLm {
+    pub fn load(path: &Path) -> Result<Self> {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Failed to open language model {}", path.display()))?;
+        let reader = BufReader::new(file);
+
+        let mut words: HashMap<String, u32> = HashMap::new();
+        let mut ngrams: HashMap<Vec<u32>, (f32, f32)> = HashMap::new();
+        let mut order = 0usize;
+        let mut section: Option<usize> = None;
+
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if line == "\\data\\" || line == "\\end\\" {
+                section = None;
+                continue;
+            }
+            if let Some(n) = line.strip_prefix('\\').and_then(|l| l.strip_suffix("-grams:")) {
+                let n: usize = n.parse().context("Malformed ARPA section header")?;
+                order = order.max(n);
+                section = Some(n);
+                continue;
+            }
+
+            let Some(n) = section else { continue };
+
+            // "<log10 prob> <w1> ... <wn> [<log10 backoff>]"
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < n + 1 {
+                continue;
+            }
+            let prob: f32 = fields[0].parse().context("Malformed ARPA probability")?;
+            let backoff: f32 = fields.get(n + 1).and_then(|b| b.parse().ok()).unwrap_or(0.0);
+
+            // Normalized like the decoder's words, so cased LMs match too
+            let key: Vec<u32> = fields[1..=n]
+                .iter()
+                .map(|w| {
+                    let word = match *w {
+                        SENTENCE_START | SENTENCE_END | UNKNOWN => w.to_string(),
+                        _ => normalize_word(w),
+                    };
+                    let next_id = words.len() as u32;
+                    *words.entry(word).or_insert(next_id)
+                })
+                .collect();
+            // Entries that differed only in case merge; keep the likelier one
+            ngrams.entry(key)
+                .and_modify(|entry| if prob > entry.0 { *entry = (prob, backoff) })
+                .or_insert((prob, backoff));
+        }
+
+        if order == 0 || ngrams.is_empty() {
+            anyhow::bail!("No n-grams found in {}", path.display());
+        }
+
+        tracing::info!(
+            "Loaded {}-gram LM from {}: {} words, {} n-grams",
+            order, path.display(), words.len(), ngrams.len()
+        );
+
+        Ok(Self {
+            order,
+            start_id: words.get(SENTENCE_START).copied(),
+            end_id: words.get(SENTENCE_END).copied(),
+            unk_id: words.get(UNKNOWN).copied(),
+            words,
+            ngrams,
+        })
+    }
+
+    /// Context for the first word of an utterance.
+    pub fn start_context(&self) -> Vec<u32> {
+        self.start_id.into_iter().collect()
+    }
+
+    /// Natural-log probability of `word` after `context`, and the context to use for the
+    /// next word. Returns `None` for tokens that normalize to nothing (pure punctuation).
+    pub fn score_word(&self, context: &[u32], word: &str) -> Option<(f32, Vec<u32>)> {
+        let normalized = normalize_word(word);
+        if normalized.is_empty() {
+            return None;
+        }
+        let id = self.words.get(&normalized).copied().or(self.unk_id);
+        let log10 = match id {
+            Some(id) => self.log10_prob(context, id),
+            None => OOV_LOG10_PROB,
+        };
+
+        let mut next_context = context.to_vec();
+        next_context.push(id.unwrap_or(u32::MAX));
+        let keep = self.order.saturating_sub(1);
+        if next_context.len() > keep {
+            next_context.drain(..next_context.len() - keep);
+        }
+        Some((log10 * std::f32::consts::LN_10, next_context))
+    }
+
+    /// Natural-log probability of ending the utterance after `context`.
+    pub fn score_end(&self, context: &[u32]) -> f32 {
+        match self.end_id {
+            Some(id) => self.log10_prob(context, id) * std::f32::consts::LN_10,
+            None => 0.0,
+        }
+    }
+
+    /// Katz back-off: use the longest matching n-gram, adding the back-off weights of the
+    /// contexts that had to be shortened.
+    fn log10_prob(&self, context: &[u32], word: u32) -> f32 {
+        let mut backoff = 0.0f32;
+        for start in 0..=context.len() {
+            let ctx = &context[start..];
+            let mut key = ctx.to_vec();
+            key.push(word);
+            if let Some(&(prob, _)) = self.ngrams.get(&key) {
+                return prob + backoff;
+            }
+            if let Some(&(_, bo)) = self.ngrams.get(ctx) {
+                backoff += bo;
+            }
+        }
+        OOV_LOG10_PROB + backoff
+    }
+}
+
+fn normalize_word(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric() && c != '\'')
+        .to_lowercase()
+}
@@ -4,15 +4,55 @@ use anyhow::{Result, Context};
 use ort::session::Session;
 use ort::value::Tensor;
 
-use super::{Engine, SttEngine, ModelCapability, ModelInfo, AudioBuffer, TranscriptionResult};
+use super::{Engine, SttEngine, ModelCapability, ModelInfo, AudioBuffer, TranscriptionResult, TranscribeOptions, Segment, Word};
+use super::ctc_beam::CtcBeamSearch;
+use super::decoding::{CtcDecodingConfig, CtcStrategy, TdtDecodingConfig, TdtStrategy};
+use super::tdt_beam::TdtBeamSearch;
+use super::hotwords::HotwordTrie;
+use super::ngram::NgramLm;
 use crate::audio::processing::{MelConfig, mel_spectrogram, mel_num_frames};
 
+/// A pause this long between words starts a new segment.
+const SEGMENT_PAUSE_MS: u64 = 500;
+const SEGMENT_MAX_MS: u64 = 10_000;
+
 struct Vocabulary {
     tokens: Vec<String>,
     blank_id: usize,
     vocab_size: usize,
 }
 
+/// A decoded token and the encoder frames it covers (`end_frame` exclusive).
+#[derive(Clone)]
+pub(super) struct TimedToken {
+    pub(super) id: usize,
+    pub(super) start_frame: usize,
+    pub(super) end_frame: usize,
+    pub(super) prob: f32,
+}
+
+/// Prediction network LSTM state, passed to and returned by the TDT decoder_joint.
+#[derive(Clone)]
+pub(super) struct DecoderState {
+    state1: Vec<f32>,
+    shape1: Vec<i64>,
+    state2: Vec<f32>,
+    shape2: Vec<i64>,
+}
+
+impl DecoderState {
+    /// Zeros in the Parakeet TDT 0.6B layout ([2, 1, 640] each). The real shapes are
+    /// taken from the decoder outputs once a token has been emitted.
+    pub(super) fn initial() -> Self {
+        Self {
+            state1: vec![0.0; 2 * 640],
+            shape1: vec![2, 1, 640],
+            state2: vec![0.0; 2 * 640],
+            shape2: vec![2, 1, 640],
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 enum ParakeetVariant {
     Ctc,
@@ -25,6 +65,9 @@ pub struct OnnxSttEngine {
     vocabulary: Mutex<Option<Vocabulary>>,
     variant: Mutex<ParakeetVariant>,
     mel_config: Mutex<MelConfig>,
+    /// Language model for CTC beam search, cached with the path it was loaded from.
+    /// `None` for a path that failed to load, so it isn't retried on every chunk.
+    lm: Mutex<Option<(String, Option<NgramLm>)>>,
 }
 
 impl OnnxSttEngine {
@@ -35,6 +78,7 @@ impl OnnxSttEngine {
             vocabulary: Mutex::new(None),
             variant: Mutex::new(ParakeetVariant::Ctc),
             mel_config: Mutex::new(MelConfig::default()),
+            lm: Mutex::new(None),
         }
     }
 
@@ -157,9 +201,59 @@ impl OnnxSttEngine {
         }
     }
 
-    fn ctc_decode(logits: &[f32], time_steps: usize, vocab_size: usize, vocab: &Vocabulary) -> String {
+    /// Greedy or beam search depending on `config`. A language model that fails to load
+    /// is logged and beam search continues without it.
+    fn ctc_decode_with(
+        &self,
+        logits: &[f32],
+        time_steps: usize,
+        vocab_size: usize,
+        vocab: &Vocabulary,
+        config: &CtcDecodingConfig,
+        hotwords: Option<&HotwordTrie>,
+    ) -> Vec<TimedToken> {
+        if config.strategy == CtcStrategy::Greedy {
+            return Self::ctc_decode(logits, time_steps, vocab_size, vocab, hotwords);
+        }
+
+        let mut lm_guard = self.lm.lock().unwrap();
+        match config.lm_path.as_deref() {
+            Some(path) if lm_guard.as_ref().map(|(p, _)| p.as_str()) != Some(path) => {
+                let lm = match NgramLm::load(Path::new(path)) {
+                    Ok(lm) => Some(lm),
+                    Err(e) => {
+                        tracing::error!("Failed to load language model {}: {}", path, e);
+                        None
+                    }
+                };
+                *lm_guard = Some((path.to_string(), lm));
+            }
+            None => *lm_guard = None,
+            _ => {}
+        }
+
+        let search = CtcBeamSearch {
+            pieces: &vocab.tokens,
+            blank_id: vocab.blank_id,
+            beam_width: config.beam_width,
+            lm: lm_guard.as_ref().and_then(|(_, lm)| lm.as_ref()),
+            lm_weight: config.lm_weight,
+            word_insertion_bonus: config.word_insertion_bonus,
+            hotwords,
+        };
+        search.decode(logits, time_steps, vocab_size)
+    }
+
+    fn ctc_decode(
+        logits: &[f32],
+        time_steps: usize,
+        vocab_size: usize,
+        vocab: &Vocabulary,
+        hotwords: Option<&HotwordTrie>,
+    ) -> Vec<TimedToken> {
         let mut prev_token: Option<usize> = None;
-        let mut result_tokens: Vec<&str> = Vec::new();
+        let mut hotword_node = hotwords.map(|h| h.root()).unwrap_or_default();
+        let mut result_tokens: Vec<TimedToken> = Vec::new();
 
         for t in 0..time_steps {
             let frame_start = t * vocab_size;
@@ -167,11 +261,11 @@ impl OnnxSttEngine {
             if frame_end > logits.len() { break; }
             let frame = &logits[frame_start..frame_end];
 
-            let token_id = frame.iter()
-                .enumerate()
-                .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
-                .map(|(i, _)| i)
-                .unwrap_or(vocab.blank_id);
+            let best = match hotwords {
+                Some(h) => h.biased_argmax(frame, hotword_node),
+                None => argmax_prob(frame),
+            };
+            let (token_id, prob) = best.unwrap_or((vocab.blank_id, 0.0));
 
             if token_id == vocab.blank_id {
                 prev_token = None;
@@ -179,22 +273,130 @@ impl OnnxSttEngine {
             }
 
             if Some(token_id) == prev_token {
+                // Repeated frames of the same token extend it
+                if let Some(last) = result_tokens.last_mut().filter(|l| l.id == token_id) {
+                    last.end_frame = t + 1;
+                    last.prob = last.prob.max(prob);
+                }
                 continue;
             }
 
             prev_token = Some(token_id);
 
             if token_id < vocab.tokens.len() {
-                result_tokens.push(&vocab.tokens[token_id]);
+                if let Some(h) = hotwords {
+                    hotword_node = h.advance(hotword_node, token_id).0;
+                }
+                result_tokens.push(TimedToken { id: token_id, start_frame: t, end_frame: t + 1, prob });
             }
         }
 
-        let raw = result_tokens.join("");
-        raw.replace('\u{2581}', " ").trim().to_string()
+        result_tokens
+    }
+
+    /// One decoder_joint call: joins encoder frame `t` with the prediction network output
+    /// for `prev_token` under `state`. Returns `[vocab_size + num_durations]` logits and
+    /// the LSTM state after consuming `prev_token`.
+    fn tdt_step(
+        decoder_session: &mut Session,
+        encoder_frame: &[f32],
+        prev_token: i32,
+        state: &DecoderState,
+    ) -> Result<(Vec<f32>, DecoderState)> {
+        let encoder_dim = encoder_frame.len();
+
+        let enc_tensor = Tensor::from_array((
+            vec![1i64, encoder_dim as i64, 1i64],
+            encoder_frame.to_vec(),
+        )).context("Failed to create encoder frame tensor")?;
+
+        // targets and target_length must be int32 (per ONNX model spec)
+        let targets_tensor = Tensor::from_array((
+            vec![1i64, 1i64],
+            vec![prev_token],  // i32
+        )).context("Failed to create targets tensor")?;
+
+        let target_length_tensor = Tensor::from_array((
+            vec![1i64],
+            vec![1i32],  // int32
+        )).context("Failed to create target length tensor")?;
+
+        let state1_tensor = Tensor::from_array((
+            state.shape1.clone(),
+            state.state1.clone(),
+        )).context("Failed to create state1 tensor")?;
+
+        let state2_tensor = Tensor::from_array((
+            state.shape2.clone(),
+            state.state2.clone(),
+        )).context("Failed to create state2 tensor")?;
+
+        let outputs = decoder_session.run(ort::inputs![
+            "encoder_outputs" => enc_tensor,
+            "targets" => targets_tensor,
+            "target_length" => target_length_tensor,
+            "input_states_1" => state1_tensor,
+            "input_states_2" => state2_tensor,
+        ]).context("TDT decoder_joint inference failed")?;
+
+        let logits_value = outputs.get("outputs")
+            .context("No 'outputs' tensor from decoder_joint")?;
+        let new_state1_value = outputs.get("output_states_1")
+            .context("No 'output_states_1' tensor")?;
+        let new_state2_value = outputs.get("output_states_2")
+            .context("No 'output_states_2' tensor")?;
+
+        let (_logits_shape, logits_data) = logits_value.try_extract_tensor::<f32>()
+            .context("Failed to extract decoder logits")?;
+        let (s1_shape, s1_data) = new_state1_value.try_extract_tensor::<f32>()
+            .context("Failed to extract state1")?;
+        let (s2_shape, s2_data) = new_state2_value.try_extract_tensor::<f32>()
+            .context("Failed to extract state2")?;
+
+        Ok((logits_data.to_vec(), DecoderState {
+            state1: s1_data.to_vec(),
+            shape1: s1_shape.iter().copied().collect(),
+            state2: s2_data.to_vec(),
+            shape2: s2_shape.iter().copied().collect(),
+        }))
+    }
+
+    /// Greedy or beam search depending on `config`.
+    fn tdt_decode_with(
+        decoder_session: &mut Session,
+        encoder_out: &[f32],
+        encoded_length: usize,
+        encoder_dim: usize,
+        vocab: &Vocabulary,
+        config: &TdtDecodingConfig,
+        hotwords: Option<&HotwordTrie>,
+    ) -> Result<Vec<TimedToken>> {
+        let dec_input_names: Vec<String> = decoder_session.inputs().iter()
+            .map(|i| i.name().to_string()).collect();
+        let dec_output_names: Vec<String> = decoder_session.outputs().iter()
+            .map(|o| o.name().to_string()).collect();
+        tracing::info!("TDT decoder inputs: {:?}", dec_input_names);
+        tracing::info!("TDT decoder outputs: {:?}", dec_output_names);
+
+        match config.strategy {
+            TdtStrategy::Greedy => Self::tdt_decode(
+                decoder_session, encoder_out, encoded_length, encoder_dim, vocab, config.max_symbols_per_step, hotwords,
+            ),
+            TdtStrategy::BeamSearch => TdtBeamSearch {
+                blank_id: vocab.blank_id,
+                vocab_size: vocab.vocab_size,
+                num_tokens: vocab.tokens.len(),
+                beam_width: config.beam_width,
+                max_symbols_per_step: config.max_symbols_per_step,
+                hotwords,
+            }.decode(encoder_out, encoded_length, encoder_dim, |frame, prev_token, state| {
+                Self::tdt_step(decoder_session, frame, prev_token, state)
+            }),
+        }
     }
 
     /// NeMo TDT transducer decoding: for each encoder time step, the decoder_joint
-    /// predicts a token + duration (0-4 frames to skip). Non-blank tokens are emitted,
+    /// predicts a token + duration (frames to skip). Non-blank tokens are emitted,
     /// LSTM state is only updated on non-blank emissions.
     fn tdt_decode(
         decoder_session: &mut Session,
@@ -202,22 +404,12 @@ impl OnnxSttEngine {
         encoded_length: usize,    // T' (number of encoder time steps)
         encoder_dim: usize,       // D (encoder output dimension)
         vocab: &Vocabulary,
-    ) -> Result<String> {
-        let max_tokens_per_step = 10;
-        let num_tdt_durations = 5;
-
-        // LSTM state dimensions — initial guess [2, 1, 640] based on Parakeet TDT 0.6B v3,
-        // auto-detected from first decoder output
-        let mut s1_dim0: usize = 2;
-        let mut s1_dim2: usize = 640;
-        let mut s2_dim0: usize = 2;
-        let mut s2_dim2: usize = 640;
-        let mut state_dims_detected = false;
-
-        let mut state1 = vec![0.0f32; s1_dim0 * s1_dim2];
-        let mut state2 = vec![0.0f32; s2_dim0 * s2_dim2];
-
-        let mut result_tokens: Vec<String> = Vec::new();
+        max_symbols_per_step: usize,
+        hotwords: Option<&HotwordTrie>,
+    ) -> Result<Vec<TimedToken>> {
+        let mut state = DecoderState::initial();
+        let mut hotword_node = hotwords.map(|h| h.root()).unwrap_or_default();
+        let mut result_tokens: Vec<TimedToken> = Vec::new();
         // NOTE: targets input expects int32, not int64
         let mut prev_token_id: i32 = vocab.blank_id as i32;
         let mut t: usize = 0;
@@ -227,144 +419,152 @@ impl OnnxSttEngine {
             let frame_start = t * encoder_dim;
             let frame_end = frame_start + encoder_dim;
             if frame_end > encoder_out.len() { break; }
-            let encoder_frame: Vec<f32> = encoder_out[frame_start..frame_end].to_vec();
-
-            let enc_tensor = Tensor::from_array((
-                vec![1i64, encoder_dim as i64, 1i64],
-                encoder_frame,
-            )).context("Failed to create encoder frame tensor")?;
-
-            // targets and target_length must be int32 (per ONNX model spec)
-            let targets_tensor = Tensor::from_array((
-                vec![1i64, 1i64],
-                vec![prev_token_id],  // i32
-            )).context("Failed to create targets tensor")?;
-
-            let target_length_tensor = Tensor::from_array((
-                vec![1i64],
-                vec![1i32],  // int32
-            )).context("Failed to create target length tensor")?;
-
-            let state1_tensor = Tensor::from_array((
-                vec![s1_dim0 as i64, 1i64, s1_dim2 as i64],
-                state1.clone(),
-            )).context("Failed to create state1 tensor")?;
-
-            let state2_tensor = Tensor::from_array((
-                vec![s2_dim0 as i64, 1i64, s2_dim2 as i64],
-                state2.clone(),
-            )).context("Failed to create state2 tensor")?;
-
-            if t == 0 {
-                tracing::info!("TDT decoder first call: encoder_frame=[1, {}, 1], targets=[[{}]], state1=[{}, 1, {}], state2=[{}, 1, {}]",
-                    encoder_dim, prev_token_id, s1_dim0, s1_dim2, s2_dim0, s2_dim2);
-
-                let dec_input_names: Vec<String> = decoder_session.inputs().iter()
-                    .map(|i| i.name().to_string()).collect();
-                let dec_output_names: Vec<String> = decoder_session.outputs().iter()
-                    .map(|o| o.name().to_string()).collect();
-                tracing::info!("TDT decoder inputs: {:?}", dec_input_names);
-                tracing::info!("TDT decoder outputs: {:?}", dec_output_names);
-            }
 
-            let outputs = match decoder_session.run(ort::inputs![
-                "encoder_outputs" => enc_tensor,
-                "targets" => targets_tensor,
-                "target_length" => target_length_tensor,
-                "input_states_1" => state1_tensor,
-                "input_states_2" => state2_tensor,
-            ]) {
-                Ok(o) => o,
-                Err(e) => {
-                    tracing::error!("TDT decoder_joint inference failed at t={}: {}", t, e);
-                    anyhow::bail!("TDT decoder_joint inference failed at t={}: {}", t, e);
-                }
-            };
+            let (logits_data, new_state) = Self::tdt_step(
+                decoder_session, &encoder_out[frame_start..frame_end], prev_token_id, &state,
+            ).with_context(|| format!("TDT decoding failed at t={}", t))?;
 
-            let logits_value = outputs.get("outputs")
-                .context("No 'outputs' tensor from decoder_joint")?;
-            let new_state1_value = outputs.get("output_states_1")
-                .context("No 'output_states_1' tensor")?;
-            let new_state2_value = outputs.get("output_states_2")
-                .context("No 'output_states_2' tensor")?;
-
-            let (_logits_shape, logits_data) = logits_value.try_extract_tensor::<f32>()
-                .context("Failed to extract decoder logits")?;
-            let (s1_shape, s1_data) = new_state1_value.try_extract_tensor::<f32>()
-                .context("Failed to extract state1")?;
-            let (s2_shape, s2_data) = new_state2_value.try_extract_tensor::<f32>()
-                .context("Failed to extract state2")?;
-
-            if !state_dims_detected {
-                let s1_dims: Vec<usize> = s1_shape.iter().map(|&d| d as usize).collect();
-                let s2_dims: Vec<usize> = s2_shape.iter().map(|&d| d as usize).collect();
-                tracing::info!("TDT decoder output state1 shape: {:?}, state2 shape: {:?}", s1_dims, s2_dims);
-                tracing::info!("TDT decoder logits size: {}, vocab_size: {}", logits_data.len(), vocab.vocab_size);
-
-                if s1_dims.len() == 3 {
-                    s1_dim0 = s1_dims[0];
-                    s1_dim2 = s1_dims[2];
-                }
-                if s2_dims.len() == 3 {
-                    s2_dim0 = s2_dims[0];
-                    s2_dim2 = s2_dims[2];
-                }
-                state_dims_detected = true;
+            if t == 0 {
+                tracing::info!("TDT decoder state shapes: {:?} / {:?}, logits size: {}, vocab_size: {}",
+                    new_state.shape1, new_state.shape2, logits_data.len(), vocab.vocab_size);
             }
 
-            // Output: [vocab_size + num_tdt_durations] logits
-            let token_logits = if logits_data.len() >= vocab.vocab_size {
-                &logits_data[..vocab.vocab_size]
-            } else {
+            // Output: [vocab_size + num_durations] logits
+            if logits_data.len() < vocab.vocab_size {
                 tracing::warn!("Decoder output too small: {} < vocab_size {}", logits_data.len(), vocab.vocab_size);
                 break;
-            };
-
-            let duration_logits = if logits_data.len() >= vocab.vocab_size + num_tdt_durations {
-                &logits_data[vocab.vocab_size..vocab.vocab_size + num_tdt_durations]
-            } else {
-                &logits_data[0..0]
-            };
+            }
+            let (token_logits, duration_logits) = logits_data.split_at(vocab.vocab_size);
 
-            let token_id = token_logits.iter()
-                .enumerate()
-                .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
-                .map(|(i, _)| i)
-                .unwrap_or(vocab.blank_id);
-
-            let step: usize = if !duration_logits.is_empty() {
-                duration_logits.iter()
-                    .enumerate()
-                    .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
-                    .map(|(i, _)| i)
-                    .unwrap_or(0)
-            } else {
-                0
+            let best = match hotwords {
+                Some(h) => h.biased_argmax(token_logits, hotword_node),
+                None => argmax_prob(token_logits),
             };
+            let (token_id, prob) = best.unwrap_or((vocab.blank_id, 0.0));
+            let step = argmax_prob(duration_logits).map(|(i, _)| i).unwrap_or(0);
 
             if token_id != vocab.blank_id && token_id < vocab.tokens.len() {
-                state1 = s1_data.to_vec();
-                state2 = s2_data.to_vec();
+                state = new_state;
                 prev_token_id = token_id as i32;
-                result_tokens.push(vocab.tokens[token_id].clone());
+                if let Some(h) = hotwords {
+                    hotword_node = h.advance(hotword_node, token_id).0;
+                }
+                result_tokens.push(TimedToken {
+                    id: token_id,
+                    start_frame: t,
+                    end_frame: (t + step.max(1)).min(encoded_length),
+                    prob,
+                });
                 emitted_this_step += 1;
             }
 
             if step > 0 {
                 t += step;
                 emitted_this_step = 0;
-            } else if token_id == vocab.blank_id || emitted_this_step >= max_tokens_per_step {
+            } else if token_id == vocab.blank_id || emitted_this_step >= max_symbols_per_step {
                 t += 1;
                 emitted_this_step = 0;
             }
             // Otherwise (non-blank token with step=0), stay on same frame
         }
 
-        let raw = result_tokens.join("");
-        Ok(raw.replace('\u{2581}', " ").trim().to_string())
+        Ok(result_tokens)
     }
 }
 
+/// Index of the largest logit and its softmax probability.
+pub(super) fn argmax_prob(logits: &[f32]) -> Option<(usize, f32)> {
+    let (best, &max) = logits.iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))?;
+    let denom: f32 = logits.iter().map(|&l| (l - max).exp()).sum();
+    Some((best, 1.0 / denom))
+}
+
+fn tokens_to_text(tokens: &[TimedToken], vocab: &Vocabulary) -> String {
+    let raw: String = tokens.iter()
+        .filter_map(|t| vocab.tokens.get(t.id))
+        .map(String::as_str)
+        .collect();
+    raw.replace('\u{2581}', " ").trim().to_string()
+}
+
+/// Merges SentencePiece pieces into words: a piece starting with `▁` opens a new word.
+/// Word confidence is the mean of its pieces' probabilities.
+fn tokens_to_words(tokens: &[TimedToken], vocab: &Vocabulary, frame_ms: f64) -> Vec<Word> {
+    let mut words: Vec<Word> = Vec::new();
+    let mut probs: Vec<f32> = Vec::new();
+
+    let close = |words: &mut Vec<Word>, probs: &mut Vec<f32>| {
+        if let Some(word) = words.last_mut() {
+            if !probs.is_empty() {
+                word.confidence = probs.iter().sum::<f32>() / probs.len() as f32;
+            }
+        }
+        probs.clear();
+    };
+
+    for token in tokens {
+        let Some(piece) = vocab.tokens.get(token.id) else { continue };
+        let start_ms = (token.start_frame as f64 * frame_ms) as u64;
+        let end_ms = (token.end_frame as f64 * frame_ms) as u64;
+
+        if piece.starts_with('\u{2581}') || words.is_empty() {
+            close(&mut words, &mut probs);
+            words.push(Word {
+                start_ms,
+                end_ms,
+                text: piece.trim_start_matches('\u{2581}').to_string(),
+                confidence: 0.0,
+            });
+        } else if let Some(word) = words.last_mut() {
+            word.text.push_str(piece);
+            word.end_ms = end_ms;
+        }
+        probs.push(token.prob);
+    }
+    close(&mut words, &mut probs);
+
+    words.retain(|w| !w.text.is_empty());
+    words
+}
+
+/// Groups words into caption-sized segments, ending one at sentence punctuation, at a
+/// pause of `SEGMENT_PAUSE_MS` or once it reaches `SEGMENT_MAX_MS`.
+fn words_to_segments(words: &[Word]) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut current: Option<Segment> = None;
+
+    for word in words {
+        if let Some(segment) = current.as_mut() {
+            if word.start_ms.saturating_sub(segment.end_ms) >= SEGMENT_PAUSE_MS
+                || word.end_ms.saturating_sub(segment.start_ms) > SEGMENT_MAX_MS
+            {
+                segments.extend(current.take());
+            }
+        }
+        match current.as_mut() {
+            Some(segment) => {
+                segment.text.push(' ');
+                segment.text.push_str(&word.text);
+                segment.end_ms = word.end_ms;
+            }
+            None => {
+                current = Some(Segment {
+                    start_ms: word.start_ms,
+                    end_ms: word.end_ms,
+                    text: word.text.clone(),
+                });
+            }
+        }
+        if word.text.ends_with(['.', '!', '?']) {
+            segments.extend(current.take());
+        }
+    }
+    segments.extend(current);
+    segments
+}
+
 impl Engine for OnnxSttEngine {
     fn load_model(&mut self, model_path: &Path, info: &ModelInfo) -> Result<()> {
         let model_dir = if model_path.is_dir() {
@@ -476,6 +676,7 @@ impl Engine for OnnxSttEngine {
         *self.encoder_session.lock().unwrap() = None;
         *self.decoder_session.lock().unwrap() = None;
         *self.vocabulary.lock().unwrap() = None;
+        *self.lm.lock().unwrap() = None;
         Ok(())
     }
 
@@ -489,12 +690,19 @@ impl Engine for OnnxSttEngine {
 }
 
 impl SttEngine for OnnxSttEngine {
-    fn transcribe(&self, audio: &AudioBuffer, language: Option<&str>) -> Result<TranscriptionResult> {
+    fn transcribe(&self, audio: &AudioBuffer, options: &TranscribeOptions) -> Result<TranscriptionResult> {
+        if options.translate {
+            anyhow::bail!("Parakeet models cannot translate. Select a Whisper model to dictate in English.");
+        }
+        let language = options.language.as_deref();
+        let ctc_config = &options.decoding.ctc;
+        let tdt_config = &options.decoding.tdt;
         let variant = self.variant.lock().unwrap().clone();
         let mel_cfg = self.mel_config.lock().unwrap().clone();
 
         let vocab_guard = self.vocabulary.lock().unwrap();
         let vocab = vocab_guard.as_ref().context("Vocabulary not loaded")?;
+        let hotwords = HotwordTrie::build(&options.hotwords, &vocab.tokens, options.hotword_boost);
 
         let start = std::time::Instant::now();
 
@@ -503,7 +711,7 @@ impl SttEngine for OnnxSttEngine {
 
         tracing::info!("Mel spectrogram: {} mels x {} frames ({} values)", mel_cfg.n_mels, n_frames, mel_flat.len());
 
-        let text = match variant {
+        let (tokens, encoder_frames) = match variant {
             ParakeetVariant::Ctc => {
                 let mut session_guard = self.encoder_session.lock().unwrap();
                 let session = session_guard.as_mut().context("CTC model not loaded")?;
@@ -545,9 +753,9 @@ impl SttEngine for OnnxSttEngine {
                 if dims.len() == 3 {
                     let time_steps = dims[1];
                     let vsize = dims[2];
-                    Self::ctc_decode(&logits_data[..time_steps * vsize], time_steps, vsize, vocab)
+                    (self.ctc_decode_with(&logits_data[..time_steps * vsize], time_steps, vsize, vocab, ctc_config, hotwords.as_ref()), time_steps)
                 } else if dims.len() == 2 {
-                    Self::ctc_decode(logits_data, dims[0], dims[1], vocab)
+                    (self.ctc_decode_with(logits_data, dims[0], dims[1], vocab, ctc_config, hotwords.as_ref()), dims[0])
                 } else {
                     anyhow::bail!("Unexpected CTC output shape: {:?}", dims);
                 }
@@ -624,10 +832,18 @@ impl SttEngine for OnnxSttEngine {
                 let mut dec_guard = self.decoder_session.lock().unwrap();
                 let decoder = dec_guard.as_mut().context("TDT decoder_joint not loaded")?;
 
-                Self::tdt_decode(decoder, &encoder_out, encoded_length, encoder_dim, vocab)?
+                (Self::tdt_decode_with(decoder, &encoder_out, encoded_length, encoder_dim, vocab, tdt_config, hotwords.as_ref())?, encoded_length)
             }
         };
 
+        let text = tokens_to_text(&tokens, vocab);
+
+        // Encoder frames evenly cover the input audio (8x subsampled 10ms hops for FastConformer)
+        let audio_ms = audio.samples.len() as f64 * 1000.0 / audio.sample_rate as f64;
+        let frame_ms = if encoder_frames > 0 { audio_ms / encoder_frames as f64 } else { 0.0 };
+        let words = tokens_to_words(&tokens, vocab, frame_ms);
+        let segments = words_to_segments(&words);
+
         let duration_ms = start.elapsed().as_millis() as u64;
         tracing::info!("Transcription ({}ms): \"{}\"", duration_ms, text);
 
@@ -635,7 +851,9 @@ impl SttEngine for OnnxSttEngine {
             text,
             language: language.map(|l| l.to_string()),
             duration_ms,
-            segments: None,
+            segments: if segments.is_empty() { None } else { Some(segments) },
+            words: Some(words),
+            translated: false,
         })
     }
 }
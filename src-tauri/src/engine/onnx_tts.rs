@@ -1,30 +1,309 @@
-use std::path::Path;
-use anyhow::Result;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use anyhow::{Result, Context};
+use ort::session::Session;
+use ort::value::Tensor;
+use serde::Deserialize;
 
 use super::{Engine, TtsEngine, ModelCapability, ModelInfo, AudioBuffer, TtsOptions};
 
+const PAD: &str = "_";
+const BOS: &str = "^";
+const EOS: &str = "$";
+const SENTENCE_SILENCE_S: f32 = 0.2;
+
+/// Piper voice config, as shipped next to the model in `<voice>.onnx.json`.
+#[derive(Debug, Clone, Deserialize)]
+struct PiperConfig {
+    audio: PiperAudioConfig,
+    #[serde(default)]
+    espeak: Option<PiperEspeakConfig>,
+    #[serde(default)]
+    inference: PiperInferenceConfig,
+    #[serde(default = "default_phoneme_type")]
+    phoneme_type: String,
+    phoneme_id_map: HashMap<String, Vec<i64>>,
+    #[serde(default = "default_num_speakers")]
+    num_speakers: u32,
+    #[serde(default)]
+    speaker_id_map: HashMap<String, i64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct PiperAudioConfig {
+    sample_rate: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct PiperEspeakConfig {
+    voice: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct PiperInferenceConfig {
+    #[serde(default = "default_noise_scale")]
+    noise_scale: f32,
+    #[serde(default = "default_length_scale")]
+    length_scale: f32,
+    #[serde(default = "default_noise_w")]
+    noise_w: f32,
+}
+
+impl Default for PiperInferenceConfig {
+    fn default() -> Self {
+        Self {
+            noise_scale: default_noise_scale(),
+            length_scale: default_length_scale(),
+            noise_w: default_noise_w(),
+        }
+    }
+}
+
+fn default_phoneme_type() -> String {
+    "espeak".to_string()
+}
+
+fn default_num_speakers() -> u32 {
+    1
+}
+
+fn default_noise_scale() -> f32 {
+    0.667
+}
+
+fn default_length_scale() -> f32 {
+    1.0
+}
+
+fn default_noise_w() -> f32 {
+    0.8
+}
+
 pub struct OnnxTtsEngine {
-    loaded: bool,
+    session: Mutex<Option<Session>>,
+    config: Mutex<Option<PiperConfig>>,
 }
 
 impl OnnxTtsEngine {
     pub fn new() -> Self {
-        Self { loaded: false }
+        Self {
+            session: Mutex::new(None),
+            config: Mutex::new(None),
+        }
+    }
+
+    /// Resolves the `.onnx` voice file and its `.onnx.json` config from a model directory
+    /// (or directly from the `.onnx` file path).
+    fn find_voice_files(model_path: &Path) -> Result<(PathBuf, PathBuf)> {
+        let onnx_path = if model_path.is_dir() {
+            std::fs::read_dir(model_path)?
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .find(|p| p.extension().is_some_and(|ext| ext == "onnx"))
+                .ok_or_else(|| anyhow::anyhow!("No .onnx voice file found in {}", model_path.display()))?
+        } else {
+            model_path.to_path_buf()
+        };
+
+        let mut config_name = onnx_path.as_os_str().to_os_string();
+        config_name.push(".json");
+        let config_path = PathBuf::from(config_name);
+        if !config_path.exists() {
+            anyhow::bail!("Voice config not found: {}", config_path.display());
+        }
+
+        Ok((onnx_path, config_path))
+    }
+
+    /// Converts text to IPA phonemes with espeak-ng, one entry per sentence/clause.
+    fn phonemize_espeak(text: &str, voice: &str) -> Result<Vec<String>> {
+        use std::io::Write;
+        use std::process::{Command, Stdio};
+
+        let mut child = Command::new("espeak-ng")
+            .args(["-q", "-b", "1", "--ipa", "-v", voice])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .context("Failed to run espeak-ng — it is required for Piper voices (install espeak-ng)")?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(text.as_bytes())?;
+        }
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            anyhow::bail!("espeak-ng exited with {}", output.status);
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .lines()
+            .map(strip_language_flags)
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect())
+    }
+
+    /// For `phoneme_type: "text"` voices, the id map is keyed by characters directly.
+    fn phonemize_text(text: &str) -> Vec<String> {
+        text.split(['\n', '.', '!', '?'])
+            .map(|s| s.trim().to_lowercase())
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+
+    /// Piper encoding: BOS, then each phoneme followed by PAD, then EOS.
+    /// Phonemes missing from the map are skipped.
+    fn phonemes_to_ids(phonemes: &str, id_map: &HashMap<String, Vec<i64>>) -> Vec<i64> {
+        let pad = id_map.get(PAD).cloned().unwrap_or_default();
+        let mut ids: Vec<i64> = Vec::new();
+
+        if let Some(bos) = id_map.get(BOS) {
+            ids.extend(bos);
+        }
+        ids.extend(&pad);
+
+        let mut buf = [0u8; 4];
+        for ch in phonemes.chars() {
+            match id_map.get(ch.encode_utf8(&mut buf) as &str) {
+                Some(ph_ids) => {
+                    ids.extend(ph_ids);
+                    ids.extend(&pad);
+                }
+                None => {
+                    tracing::debug!("Piper: phoneme {:?} not in id map, skipping", ch);
+                }
+            }
+        }
+
+        if let Some(eos) = id_map.get(EOS) {
+            ids.extend(eos);
+        }
+        ids
+    }
+
+    fn resolve_speaker_id(config: &PiperConfig, voice_id: Option<&str>) -> Option<i64> {
+        if config.num_speakers <= 1 {
+            return None;
+        }
+        let id = voice_id
+            .and_then(|v| {
+                config.speaker_id_map.get(v).copied()
+                    .or_else(|| v.parse::<i64>().ok())
+            })
+            .unwrap_or(0);
+        Some(id.clamp(0, config.num_speakers as i64 - 1))
+    }
+
+    fn run_vits(
+        session: &mut Session,
+        phoneme_ids: Vec<i64>,
+        scales: [f32; 3],
+        speaker_id: Option<i64>,
+    ) -> Result<Vec<f32>> {
+        let n = phoneme_ids.len();
+
+        let input_tensor = Tensor::from_array((
+            vec![1i64, n as i64],
+            phoneme_ids,
+        )).context("Failed to create phoneme tensor")?;
+
+        let lengths_tensor = Tensor::from_array((
+            vec![1i64],
+            vec![n as i64],
+        )).context("Failed to create input_lengths tensor")?;
+
+        let scales_tensor = Tensor::from_array((
+            vec![3i64],
+            scales.to_vec(),
+        )).context("Failed to create scales tensor")?;
+
+        let outputs = match speaker_id {
+            Some(sid) => {
+                let sid_tensor = Tensor::from_array((
+                    vec![1i64],
+                    vec![sid],
+                )).context("Failed to create sid tensor")?;
+                session.run(ort::inputs![
+                    "input" => input_tensor,
+                    "input_lengths" => lengths_tensor,
+                    "scales" => scales_tensor,
+                    "sid" => sid_tensor,
+                ]).context("Piper inference failed")?
+            }
+            None => {
+                session.run(ort::inputs![
+                    "input" => input_tensor,
+                    "input_lengths" => lengths_tensor,
+                    "scales" => scales_tensor,
+                ]).context("Piper inference failed")?
+            }
+        };
+
+        let audio_value = outputs.get("output")
+            .context("No 'output' tensor from Piper model")?;
+        let (_shape, audio_data) = audio_value.try_extract_tensor::<f32>()
+            .context("Failed to extract Piper audio")?;
+
+        Ok(audio_data.to_vec())
     }
 }
 
+/// espeak-ng marks language switches as `(en)` … `(fr)`; Piper drops them.
+fn strip_language_flags(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut in_flag = false;
+    for ch in line.chars() {
+        match ch {
+            '(' => in_flag = true,
+            ')' if in_flag => in_flag = false,
+            _ if !in_flag => out.push(ch),
+            _ => {}
+        }
+    }
+    out
+}
+
 impl Engine for OnnxTtsEngine {
-    fn load_model(&mut self, _model_path: &Path, _info: &ModelInfo) -> Result<()> {
-        todo!("ONNX TTS engine - Phase 6")
+    fn load_model(&mut self, model_path: &Path, _info: &ModelInfo) -> Result<()> {
+        let (onnx_path, config_path) = Self::find_voice_files(model_path)?;
+
+        let config_data = std::fs::read_to_string(&config_path)
+            .context("Failed to read Piper voice config")?;
+        let config: PiperConfig = serde_json::from_str(&config_data)
+            .context("Failed to parse Piper voice config")?;
+
+        tracing::info!("Loading Piper voice from {}", onnx_path.display());
+        let session = Session::builder()?
+            .with_intra_threads(4)?
+            .commit_from_file(&onnx_path)
+            .context("Failed to load Piper ONNX model")?;
+
+        for input in session.inputs() {
+            tracing::info!("Piper input: {}", input.name());
+        }
+
+        tracing::info!(
+            "Piper voice loaded: {}Hz, phoneme_type={}, speakers={}, {} phonemes",
+            config.audio.sample_rate, config.phoneme_type, config.num_speakers, config.phoneme_id_map.len()
+        );
+
+        *self.session.lock().unwrap() = Some(session);
+        *self.config.lock().unwrap() = Some(config);
+        Ok(())
     }
 
     fn unload_model(&mut self) -> Result<()> {
-        self.loaded = false;
+        *self.session.lock().unwrap() = None;
+        *self.config.lock().unwrap() = None;
         Ok(())
     }
 
     fn is_loaded(&self) -> bool {
-        self.loaded
+        self.session.lock().unwrap().is_some()
     }
 
     fn capability(&self) -> ModelCapability {
@@ -33,7 +312,62 @@ impl Engine for OnnxTtsEngine {
 }
 
 impl TtsEngine for OnnxTtsEngine {
-    fn synthesize(&self, _text: &str, _options: &TtsOptions) -> Result<AudioBuffer> {
-        todo!("ONNX TTS synthesis - Phase 6")
+    fn synthesize(&self, text: &str, options: &TtsOptions) -> Result<AudioBuffer> {
+        let config = self.config.lock().unwrap().clone()
+            .context("Voice not loaded")?;
+
+        let sentences = if config.phoneme_type == "text" {
+            Self::phonemize_text(text)
+        } else {
+            let voice = config.espeak.as_ref()
+                .map(|e| e.voice.as_str())
+                .unwrap_or(options.language.as_str());
+            Self::phonemize_espeak(text, voice)?
+        };
+
+        if sentences.is_empty() {
+            anyhow::bail!("Nothing to synthesize");
+        }
+
+        // Piper's length_scale is a duration multiplier: speed 2.0 → half the length
+        let speed = if options.speed > 0.0 { options.speed } else { 1.0 };
+        let scales = [
+            config.inference.noise_scale,
+            config.inference.length_scale / speed,
+            config.inference.noise_w,
+        ];
+        let speaker_id = Self::resolve_speaker_id(&config, options.voice_id.as_deref());
+
+        let start = std::time::Instant::now();
+        let silence_len = (SENTENCE_SILENCE_S * config.audio.sample_rate as f32) as usize;
+        let mut samples: Vec<f32> = Vec::new();
+
+        let mut session_guard = self.session.lock().unwrap();
+        let session = session_guard.as_mut().context("Voice not loaded")?;
+
+        for sentence in &sentences {
+            let ids = Self::phonemes_to_ids(sentence, &config.phoneme_id_map);
+            if ids.len() <= 3 {
+                continue;
+            }
+            let audio = Self::run_vits(session, ids, scales, speaker_id)?;
+            if !samples.is_empty() {
+                samples.extend(std::iter::repeat_n(0.0, silence_len));
+            }
+            samples.extend(audio);
+        }
+
+        tracing::info!(
+            "Piper synthesized {} sentence(s), {:.1}s of audio in {}ms",
+            sentences.len(),
+            samples.len() as f32 / config.audio.sample_rate as f32,
+            start.elapsed().as_millis()
+        );
+
+        Ok(AudioBuffer {
+            samples,
+            sample_rate: config.audio.sample_rate,
+            channels: 1,
+        })
     }
 }
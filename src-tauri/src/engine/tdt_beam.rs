@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use anyhow::Result;
+
+use super::ctc_beam::{log_add, log_softmax, top_k};
+use super::hotwords::HotwordTrie;
+use super::onnx_stt::{DecoderState, TimedToken};
+
+#[derive(Clone)]
+struct Hypothesis {
+    tokens: Vec<TimedToken>,
+    score: f32,
+    /// Prediction network state after the last emitted token.
+    state: DecoderState,
+    /// Encoder frame this hypothesis is waiting on.
+    frame: usize,
+    /// Tokens emitted on `frame` without advancing (duration 0).
+    symbols_at_frame: usize,
+    /// Hotword trie node. Biasing deltas are folded into `score`.
+    hotword: usize,
+}
+
+impl Hypothesis {
+    fn key(&self) -> (Vec<usize>, usize) {
+        (self.tokens.iter().map(|t| t.id).collect(), self.frame)
+    }
+
+    fn last_token(&self, blank_id: usize) -> i32 {
+        self.tokens.last().map(|t| t.id).unwrap_or(blank_id) as i32
+    }
+
+    /// Length-normalized score, so longer transcripts are not penalized for having
+    /// more token probabilities multiplied in.
+    fn normalized_score(&self) -> f32 {
+        self.score / self.tokens.len().max(1) as f32
+    }
+}
+
+/// Frame-synchronous beam search over TDT token and duration outputs. Each hypothesis
+/// carries its own LSTM state; a hypothesis is scored by `log P(token) + log P(duration)`
+/// summed over its emissions (blank included), plus the hotword bias when a trie is given.
+pub struct TdtBeamSearch<'a> {
+    pub blank_id: usize,
+    pub vocab_size: usize,
+    /// Number of real tokens; ids past it are ignored, as in greedy decoding.
+    pub num_tokens: usize,
+    pub beam_width: usize,
+    pub max_symbols_per_step: usize,
+    pub hotwords: Option<&'a HotwordTrie>,
+}
+
+impl TdtBeamSearch<'_> {
+    /// `encoder_out` is `[encoded_length, encoder_dim]` row-major. `step` runs the
+    /// decoder_joint for one frame, previous token and state.
+    pub fn decode<F>(
+        &self,
+        encoder_out: &[f32],
+        encoded_length: usize,
+        encoder_dim: usize,
+        mut step: F,
+    ) -> Result<Vec<TimedToken>>
+    where
+        F: FnMut(&[f32], i32, &DecoderState) -> Result<(Vec<f32>, DecoderState)>,
+    {
+        let beam_width = self.beam_width.max(1);
+        let encoded_length = encoded_length.min(encoder_out.len() / encoder_dim.max(1));
+
+        let mut pending = vec![Hypothesis {
+            tokens: Vec::new(),
+            score: 0.0,
+            state: DecoderState::initial(),
+            frame: 0,
+            symbols_at_frame: 0,
+            hotword: self.hotwords.map(|h| h.root()).unwrap_or_default(),
+        }];
+        let mut finished: Vec<Hypothesis> = Vec::new();
+
+        while let Some(t) = pending.iter().map(|h| h.frame).min() {
+            let (mut active, rest): (Vec<Hypothesis>, Vec<Hypothesis>) =
+                pending.into_iter().partition(|h| h.frame == t);
+            let mut later = rest;
+            let frame = &encoder_out[t * encoder_dim..(t + 1) * encoder_dim];
+
+            while !active.is_empty() {
+                // Expand the best hypothesis still on this frame
+                let best = active.iter().enumerate()
+                    .max_by(|a, b| a.1.score.total_cmp(&b.1.score))
+                    .map(|(i, _)| i)
+                    .unwrap_or(0);
+                let hyp = active.swap_remove(best);
+
+                let (logits, new_state) = step(frame, hyp.last_token(self.blank_id), &hyp.state)?;
+                if logits.len() <= self.vocab_size {
+                    tracing::warn!("Decoder output too small: {} <= vocab_size {}", logits.len(), self.vocab_size);
+                    continue;
+                }
+                let token_lp = log_softmax(&logits[..self.vocab_size]);
+                let duration_lp = log_softmax(&logits[self.vocab_size..]);
+
+                // Blank keeps the prediction state and must advance at least one frame
+                for (duration, &lp) in duration_lp.iter().enumerate().skip(1) {
+                    later.push(Hypothesis {
+                        frame: t + duration,
+                        symbols_at_frame: 0,
+                        score: hyp.score + token_lp[self.blank_id] + lp,
+                        ..hyp.clone()
+                    });
+                }
+
+                let mut candidates = top_k(&token_lp, beam_width, self.blank_id);
+                if let Some(h) = self.hotwords {
+                    let extra: Vec<usize> = h.continuations(hyp.hotword).into_iter()
+                        .filter(|c| *c != self.blank_id && !candidates.contains(c))
+                        .collect();
+                    candidates.extend(extra);
+                }
+
+                for token in candidates {
+                    if token >= self.num_tokens {
+                        continue;
+                    }
+                    let (hotword, bias) = match self.hotwords {
+                        Some(h) => h.advance(hyp.hotword, token),
+                        None => (0, 0.0),
+                    };
+                    for (duration, &lp) in duration_lp.iter().enumerate() {
+                        if duration == 0 && hyp.symbols_at_frame + 1 >= self.max_symbols_per_step {
+                            continue;
+                        }
+                        let mut tokens = hyp.tokens.clone();
+                        tokens.push(TimedToken {
+                            id: token,
+                            start_frame: t,
+                            end_frame: (t + duration.max(1)).min(encoded_length),
+                            prob: token_lp[token].exp(),
+                        });
+                        let child = Hypothesis {
+                            tokens,
+                            score: hyp.score + token_lp[token] + lp + bias,
+                            state: new_state.clone(),
+                            frame: t + duration,
+                            symbols_at_frame: if duration == 0 { hyp.symbols_at_frame + 1 } else { 0 },
+                            hotword,
+                        };
+                        if duration == 0 {
+                            active.push(child);
+                        } else {
+                            later.push(child);
+                        }
+                    }
+                }
+
+                active = prune(active, beam_width);
+                later = prune(later, beam_width);
+
+                // Scores only decrease with expansion, so once the beam is full of
+                // hypotheses better than anything left on this frame, stop.
+                let best_active = active.iter().map(|h| h.score).fold(f32::NEG_INFINITY, f32::max);
+                if later.len() >= beam_width && later.iter().all(|h| h.score > best_active) {
+                    break;
+                }
+            }
+
+            let (done, still_pending): (Vec<Hypothesis>, Vec<Hypothesis>) =
+                later.into_iter().partition(|h| h.frame >= encoded_length);
+            finished.extend(done);
+            finished = prune(finished, beam_width);
+            pending = still_pending;
+        }
+
+        if let Some(h) = self.hotwords {
+            for hyp in &mut finished {
+                hyp.score += h.unfinished(hyp.hotword);
+            }
+        }
+
+        Ok(finished.into_iter()
+            .max_by(|a, b| a.normalized_score().total_cmp(&b.normalized_score()))
+            .map(|h| h.tokens)
+            .unwrap_or_default())
+    }
+}
+
+/// Merges hypotheses with the same tokens waiting on the same frame (different
+/// duration paths to the same alignment) and keeps the `beam_width` best.
+fn prune(hyps: Vec<Hypothesis>, beam_width: usize) -> Vec<Hypothesis> {
+    let mut merged: HashMap<(Vec<usize>, usize), Hypothesis> = HashMap::new();
+    for hyp in hyps {
+        match merged.get_mut(&hyp.key()) {
+            Some(existing) => {
+                let score = log_add(existing.score, hyp.score);
+                if hyp.score > existing.score {
+                    *existing = hyp;
+                }
+                existing.score = score;
+            }
+            None => {
+                merged.insert(hyp.key(), hyp);
+            }
+        }
+    }
+    let mut ranked: Vec<Hypothesis> = merged.into_values().collect();
+    ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+    ranked.truncate(beam_width);
+    ranked
+}
@@ -3,7 +3,7 @@ use std::sync::Mutex;
 use anyhow::{Result, Context};
 use whisper_rs::{WhisperContext, WhisperContextParameters, WhisperState, FullParams, SamplingStrategy};
 
-use super::{Engine, SttEngine, ModelCapability, ModelInfo, AudioBuffer, TranscriptionResult, Segment};
+use super::{Engine, SttEngine, ModelCapability, ModelInfo, AudioBuffer, TranscriptionResult, TranscribeOptions, Segment, Word};
 
 pub struct WhisperSttEngine {
     context: Mutex<Option<WhisperContext>>,
@@ -19,6 +19,80 @@ impl WhisperSttEngine {
     }
 }
 
+/// Runs Whisper's language detection on the start of `samples` and returns the most
+/// probable language among `allowed`.
+fn detect_allowed_language(
+    state: &mut WhisperState,
+    samples: &[f32],
+    allowed: &[String],
+    threads: usize,
+) -> Result<Option<String>> {
+    state.pcm_to_mel(samples, threads)
+        .map_err(|e| anyhow::anyhow!("Failed to compute mel spectrogram: {}", e))?;
+    let (_, probs) = state.lang_detect(0, threads)
+        .map_err(|e| anyhow::anyhow!("Language detection failed: {}", e))?;
+
+    let best = allowed.iter()
+        .filter_map(|lang| {
+            let id = whisper_rs::get_lang_id(lang)?;
+            probs.get(id as usize).map(|&p| (lang, p))
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+
+    if let Some((lang, p)) = best {
+        tracing::info!("Detected language '{}' (p={:.2}) among {:?}", lang, p, allowed);
+    }
+    Ok(best.map(|(lang, _)| lang.clone()))
+}
+
+/// Groups a segment's text tokens into words. A token starting with a space opens a new
+/// word; special and timestamp tokens (id >= EOT) are skipped.
+fn segment_words(state: &WhisperState, segment: i32, token_eot: i32) -> Result<Vec<Word>> {
+    let n_tokens = state.full_n_tokens(segment)
+        .map_err(|e| anyhow::anyhow!("Failed to get token count: {}", e))?;
+
+    let mut words: Vec<Word> = Vec::new();
+    let mut probs: Vec<f32> = Vec::new();
+
+    for j in 0..n_tokens {
+        let data = state.full_get_token_data(segment, j)
+            .map_err(|e| anyhow::anyhow!("Failed to get token data: {}", e))?;
+        if data.id >= token_eot {
+            continue;
+        }
+        let token_text = state.full_get_token_text_lossy(segment, j)
+            .map_err(|e| anyhow::anyhow!("Failed to get token text: {}", e))?;
+
+        let starts_word = token_text.starts_with(' ') || words.is_empty();
+        if starts_word {
+            close_word(&mut words, &mut probs);
+            words.push(Word {
+                start_ms: (data.t0 * 10) as u64,
+                end_ms: (data.t1 * 10) as u64,
+                text: token_text.trim_start().to_string(),
+                confidence: 0.0,
+            });
+        } else if let Some(word) = words.last_mut() {
+            word.text.push_str(&token_text);
+            word.end_ms = (data.t1 * 10) as u64;
+        }
+        probs.push(data.p);
+    }
+    close_word(&mut words, &mut probs);
+
+    words.retain(|w| !w.text.is_empty());
+    Ok(words)
+}
+
+fn close_word(words: &mut [Word], probs: &mut Vec<f32>) {
+    if let Some(word) = words.last_mut() {
+        if !probs.is_empty() {
+            word.confidence = probs.iter().sum::<f32>() / probs.len() as f32;
+        }
+    }
+    probs.clear();
+}
+
 impl Engine for WhisperSttEngine {
     fn load_model(&mut self, model_path: &Path, _info: &ModelInfo) -> Result<()> {
         let params = WhisperContextParameters::default();
@@ -47,7 +121,8 @@ impl Engine for WhisperSttEngine {
 }
 
 impl SttEngine for WhisperSttEngine {
-    fn transcribe(&self, audio: &AudioBuffer, language: Option<&str>) -> Result<TranscriptionResult> {
+    fn transcribe(&self, audio: &AudioBuffer, options: &TranscribeOptions) -> Result<TranscriptionResult> {
+        let language = options.language.as_deref();
         let ctx_guard = self.context.lock().unwrap();
         let ctx = ctx_guard.as_ref().context("Model not loaded")?;
 
@@ -58,12 +133,59 @@ impl SttEngine for WhisperSttEngine {
                 .map_err(|e| anyhow::anyhow!("Failed to create state: {}", e))?,
         };
 
-        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+        let config = options.decoding.whisper_for(options.profile);
+        let threads = match config.n_threads {
+            0 => std::thread::available_parallelism().map(|n| n.get().min(4)).unwrap_or(4),
+            n => n,
+        };
+
+        let detected = match language {
+            // Failing here would drop the warmed-up state, so let Whisper detect instead
+            None if !options.allowed_languages.is_empty() => {
+                detect_allowed_language(&mut state, &audio.samples, &options.allowed_languages, threads)
+                    .unwrap_or_else(|e| {
+                        tracing::warn!("{}; falling back to unrestricted detection", e);
+                        None
+                    })
+            }
+            _ => None,
+        };
+        let language = language.or(detected.as_deref());
+
+        // Whisper continues the style and spelling of its prompt, so listing the
+        // hotwords makes it more likely to write them that way
+        let prompt = if options.hotwords.is_empty() {
+            None
+        } else {
+            // whisper-rs panics on interior NULs when building the C string
+            Some(format!("{}.", options.hotwords.join(", ")).replace('\0', ""))
+        };
+
+        let strategy = if config.beam_size > 1 {
+            SamplingStrategy::BeamSearch { beam_size: config.beam_size as i32, patience: -1.0 }
+        } else {
+            SamplingStrategy::Greedy { best_of: config.best_of.max(1) as i32 }
+        };
+
+        let mut params = FullParams::new(strategy);
         params.set_print_special(false);
         params.set_print_progress(false);
         params.set_print_realtime(false);
         params.set_print_timestamps(false);
+        params.set_token_timestamps(true);
         params.set_language(language.or(Some("auto")));
+        params.set_translate(options.translate);
+        params.set_temperature(config.temperature);
+        params.set_temperature_inc(config.temperature_inc);
+        params.set_entropy_thold(config.entropy_thold);
+        params.set_logprob_thold(config.logprob_thold);
+        params.set_no_speech_thold(config.no_speech_thold);
+        params.set_suppress_blank(config.suppress_blank);
+        params.set_suppress_non_speech_tokens(config.suppress_non_speech_tokens);
+        params.set_n_threads(threads as i32);
+        if let Some(prompt) = prompt.as_deref() {
+            params.set_initial_prompt(prompt);
+        }
 
         let start = std::time::Instant::now();
 
@@ -77,6 +199,8 @@ impl SttEngine for WhisperSttEngine {
 
         let mut text = String::new();
         let mut segments = Vec::new();
+        let mut words = Vec::new();
+        let token_eot = ctx.token_eot();
 
         for i in 0..num_segments {
             let segment_text = state.full_get_segment_text(i)
@@ -86,6 +210,8 @@ impl SttEngine for WhisperSttEngine {
             let end_ts = state.full_get_segment_t1(i)
                 .map_err(|e| anyhow::anyhow!("Failed to get segment end: {}", e))?;
 
+            words.extend(segment_words(&state, i, token_eot)?);
+
             text.push_str(&segment_text);
             segments.push(Segment {
                 start_ms: (start_ts * 10) as u64,
@@ -107,6 +233,8 @@ impl SttEngine for WhisperSttEngine {
             language,
             duration_ms,
             segments: Some(segments),
+            words: Some(words),
+            translated: options.translate,
         })
     }
 
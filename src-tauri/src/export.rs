@@ -0,0 +1,269 @@
+use std::path::Path;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::engine::{Segment, TranscriptionResult};
+
+/// A pause this long between segments starts a new paragraph in plain-text exports.
+const PARAGRAPH_GAP_MS: u64 = 2000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Srt,
+    Vtt,
+    Json,
+    Txt,
+}
+
+/// Caption layout limits. Defaults follow common broadcast guidelines.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportOptions {
+    #[serde(default = "default_max_line_chars")]
+    pub max_line_chars: usize,
+    #[serde(default = "default_max_lines")]
+    pub max_lines: usize,
+    #[serde(default = "default_max_cue_duration_ms")]
+    pub max_cue_duration_ms: u64,
+}
+
+fn default_max_line_chars() -> usize {
+    42
+}
+
+fn default_max_lines() -> usize {
+    2
+}
+
+fn default_max_cue_duration_ms() -> u64 {
+    7000
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            max_line_chars: default_max_line_chars(),
+            max_lines: default_max_lines(),
+            max_cue_duration_ms: default_max_cue_duration_ms(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonSegment<'a> {
+    start_ms: u64,
+    end_ms: u64,
+    start: String,
+    end: String,
+    text: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    text: &'a str,
+    language: Option<&'a str>,
+    duration_ms: u64,
+    segments: Vec<JsonSegment<'a>>,
+}
+
+pub fn render(result: &TranscriptionResult, format: ExportFormat, options: &ExportOptions) -> Result<String> {
+    match format {
+        ExportFormat::Srt => Ok(render_srt(&caption_cues(result, options)?)),
+        ExportFormat::Vtt => Ok(render_vtt(&caption_cues(result, options)?)),
+        ExportFormat::Json => render_json(result),
+        ExportFormat::Txt => Ok(render_txt(result)),
+    }
+}
+
+pub fn export_to_file(
+    result: &TranscriptionResult,
+    format: ExportFormat,
+    path: &Path,
+    options: &ExportOptions,
+) -> Result<()> {
+    let content = render(result, format, options)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content)?;
+    tracing::info!("Exported transcription as {:?} to {}", format, path.display());
+    Ok(())
+}
+
+/// Segments re-cut into caption cues that fit `options`, with text wrapped into lines.
+fn caption_cues(result: &TranscriptionResult, options: &ExportOptions) -> Result<Vec<Segment>> {
+    let segments = result.segments.as_deref()
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| anyhow::anyhow!("This transcription has no timestamps; subtitles need segment timing"))?;
+
+    let max_line_chars = options.max_line_chars.max(1);
+    let max_cue_chars = max_line_chars * options.max_lines.max(1);
+
+    Ok(segments
+        .iter()
+        .flat_map(|s| split_segment(s, max_cue_chars, options.max_cue_duration_ms))
+        .map(|mut cue| {
+            cue.text = wrap_lines(&cue.text, max_line_chars);
+            cue
+        })
+        .collect())
+}
+
+/// Splits a segment into word-aligned pieces of at most `max_chars` characters and
+/// `max_duration_ms` each. Timing is interpolated by character count.
+fn split_segment(segment: &Segment, max_chars: usize, max_duration_ms: u64) -> Vec<Segment> {
+    let words: Vec<&str> = segment.text.split_whitespace().collect();
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    let duration = segment.end_ms.saturating_sub(segment.start_ms);
+    let total_chars = words.iter().map(|w| w.chars().count() + 1).sum::<usize>().max(1);
+    let ms_per_char = duration as f64 / total_chars as f64;
+
+    let mut pieces = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    let mut current_chars = 0usize;
+    let mut chars_before = 0usize;
+
+    for word in words {
+        let word_chars = word.chars().count() + 1;
+        let projected_ms = ((current_chars + word_chars) as f64 * ms_per_char) as u64;
+        let too_long = current_chars + word_chars > max_chars + 1;
+        let too_slow = max_duration_ms > 0 && projected_ms > max_duration_ms;
+
+        if !current.is_empty() && (too_long || too_slow) {
+            pieces.push(piece(segment, &current, chars_before, current_chars, ms_per_char));
+            chars_before += current_chars;
+            current.clear();
+            current_chars = 0;
+        }
+        current.push(word);
+        current_chars += word_chars;
+    }
+    if !current.is_empty() {
+        pieces.push(piece(segment, &current, chars_before, current_chars, ms_per_char));
+    }
+
+    // The last piece always ends with the segment, whatever the rounding did
+    if let Some(last) = pieces.last_mut() {
+        last.end_ms = segment.end_ms;
+    }
+    pieces
+}
+
+fn piece(segment: &Segment, words: &[&str], chars_before: usize, chars: usize, ms_per_char: f64) -> Segment {
+    Segment {
+        start_ms: segment.start_ms + (chars_before as f64 * ms_per_char) as u64,
+        end_ms: segment.start_ms + ((chars_before + chars) as f64 * ms_per_char) as u64,
+        text: words.join(" "),
+    }
+}
+
+/// Greedy word wrap. Words longer than a line are kept whole.
+fn wrap_lines(text: &str, max_line_chars: usize) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > max_line_chars {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+fn render_srt(cues: &[Segment]) -> String {
+    let mut out = String::new();
+    for (i, cue) in cues.iter().enumerate() {
+        out.push_str(&format!(
+            "{}\n{} --> {}\n{}\n\n",
+            i + 1,
+            format_timestamp(cue.start_ms, ','),
+            format_timestamp(cue.end_ms, ','),
+            cue.text
+        ));
+    }
+    out
+}
+
+fn render_vtt(cues: &[Segment]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for cue in cues {
+        out.push_str(&format!(
+            "{} --> {}\n{}\n\n",
+            format_timestamp(cue.start_ms, '.'),
+            format_timestamp(cue.end_ms, '.'),
+            cue.text
+        ));
+    }
+    out
+}
+
+fn render_json(result: &TranscriptionResult) -> Result<String> {
+    let segments = result.segments.as_deref().unwrap_or_default()
+        .iter()
+        .map(|s| JsonSegment {
+            start_ms: s.start_ms,
+            end_ms: s.end_ms,
+            start: format_timestamp(s.start_ms, '.'),
+            end: format_timestamp(s.end_ms, '.'),
+            text: s.text.trim(),
+        })
+        .collect();
+    let export = JsonExport {
+        text: &result.text,
+        language: result.language.as_deref(),
+        duration_ms: result.duration_ms,
+        segments,
+    };
+    Ok(serde_json::to_string_pretty(&export)?)
+}
+
+/// Segment text joined into paragraphs, breaking on long pauses between segments.
+fn render_txt(result: &TranscriptionResult) -> String {
+    let segments = match result.segments.as_deref() {
+        Some(segments) if !segments.is_empty() => segments,
+        _ => return format!("{}\n", result.text.trim()),
+    };
+
+    let mut paragraphs: Vec<String> = Vec::new();
+    let mut paragraph = String::new();
+    let mut prev_end: Option<u64> = None;
+
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let long_pause = prev_end.is_some_and(|end| segment.start_ms.saturating_sub(end) >= PARAGRAPH_GAP_MS);
+        if long_pause && !paragraph.is_empty() {
+            paragraphs.push(std::mem::take(&mut paragraph));
+        }
+        if !paragraph.is_empty() {
+            paragraph.push(' ');
+        }
+        paragraph.push_str(text);
+        prev_end = Some(segment.end_ms);
+    }
+    if !paragraph.is_empty() {
+        paragraphs.push(paragraph);
+    }
+
+    format!("{}\n", paragraphs.join("\n\n"))
+}
+
+/// `HH:MM:SS,mmm` (SRT) or `HH:MM:SS.mmm` (WebVTT).
+fn format_timestamp(ms: u64, separator: char) -> String {
+    let hours = ms / 3_600_000;
+    let minutes = (ms % 3_600_000) / 60_000;
+    let seconds = (ms % 60_000) / 1000;
+    let millis = ms % 1000;
+    format!("{:02}:{:02}:{:02}{}{:03}", hours, minutes, seconds, separator, millis)
+}
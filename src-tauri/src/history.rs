@@ -0,0 +1,170 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::engine::{Segment, TranscriptionResult, Word};
+use crate::state::HistorySettings;
+
+const MS_PER_DAY: u64 = 24 * 60 * 60 * 1000;
+
+/// Serializes read-modify-write cycles on the history file.
+static HISTORY_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub id: String,
+    /// Unix time in milliseconds.
+    pub timestamp_ms: u64,
+    pub model_id: Option<String>,
+    pub language: Option<String>,
+    pub duration_ms: u64,
+    pub text: String,
+    pub segments: Option<Vec<Segment>>,
+    #[serde(default)]
+    pub words: Option<Vec<Word>>,
+    #[serde(default)]
+    pub translated: bool,
+}
+
+fn history_path() -> Result<PathBuf> {
+    let app_support = dirs::data_dir()
+        .ok_or_else(|| anyhow::anyhow!("Cannot find Application Support directory"))?;
+    Ok(app_support.join("TalkToMe").join("history.json"))
+}
+
+fn read_history() -> Result<Vec<HistoryEntry>> {
+    let path = history_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let data = std::fs::read_to_string(&path)?;
+    let entries: Vec<HistoryEntry> = serde_json::from_str(&data)?;
+    Ok(entries)
+}
+
+fn write_history(entries: &[HistoryEntry]) -> Result<()> {
+    let path = history_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let data = serde_json::to_string_pretty(entries)?;
+    // Write to a temp file first so a crash mid-write can't lose the whole history
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, data)?;
+    std::fs::rename(&tmp, &path)?;
+    Ok(())
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Drops entries older than `retention_days` and beyond `max_entries` (oldest first).
+fn apply_retention(entries: &mut Vec<HistoryEntry>, settings: &HistorySettings) {
+    if let Some(days) = settings.retention_days {
+        let cutoff = now_ms().saturating_sub(days as u64 * MS_PER_DAY);
+        entries.retain(|e| e.timestamp_ms >= cutoff);
+    }
+    if let Some(max) = settings.max_entries {
+        if entries.len() > max {
+            let excess = entries.len() - max;
+            entries.drain(..excess);
+        }
+    }
+}
+
+pub fn add_entry(
+    result: &TranscriptionResult,
+    model_id: Option<String>,
+    settings: &HistorySettings,
+) -> Result<HistoryEntry> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    let mut entries = read_history()?;
+
+    let timestamp_ms = now_ms();
+    let entry = HistoryEntry {
+        id: format!("{}-{}", timestamp_ms, entries.len()),
+        timestamp_ms,
+        model_id,
+        language: result.language.clone(),
+        duration_ms: result.duration_ms,
+        text: result.text.clone(),
+        segments: result.segments.clone(),
+        words: result.words.clone(),
+        translated: result.translated,
+    };
+    entries.push(entry.clone());
+    apply_retention(&mut entries, settings);
+    write_history(&entries)?;
+    Ok(entry)
+}
+
+/// Newest first.
+pub fn list_entries(limit: Option<usize>) -> Result<Vec<HistoryEntry>> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    let mut entries = read_history()?;
+    entries.reverse();
+    if let Some(limit) = limit {
+        entries.truncate(limit);
+    }
+    Ok(entries)
+}
+
+/// Case-insensitive search: every whitespace-separated term must appear in the text.
+/// Newest first.
+pub fn search_entries(query: &str) -> Result<Vec<HistoryEntry>> {
+    let terms: Vec<String> = query.split_whitespace().map(|t| t.to_lowercase()).collect();
+    let entries = list_entries(None)?;
+    if terms.is_empty() {
+        return Ok(entries);
+    }
+    Ok(entries
+        .into_iter()
+        .filter(|e| {
+            let text = e.text.to_lowercase();
+            terms.iter().all(|t| text.contains(t.as_str()))
+        })
+        .collect())
+}
+
+pub fn get_entry(id: &str) -> Result<HistoryEntry> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    read_history()?
+        .into_iter()
+        .find(|e| e.id == id)
+        .ok_or_else(|| anyhow::anyhow!("History entry not found: {}", id))
+}
+
+pub fn delete_entry(id: &str) -> Result<()> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    let mut entries = read_history()?;
+    let before = entries.len();
+    entries.retain(|e| e.id != id);
+    if entries.len() == before {
+        anyhow::bail!("History entry not found: {}", id);
+    }
+    write_history(&entries)
+}
+
+pub fn clear() -> Result<()> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    write_history(&[])
+}
+
+/// Re-applies retention, e.g. after the retention setting changed.
+pub fn prune(settings: &HistorySettings) -> Result<()> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    let mut entries = read_history()?;
+    let before = entries.len();
+    apply_retention(&mut entries, settings);
+    if entries.len() != before {
+        tracing::info!("History pruned: {} -> {} entries", before, entries.len());
+        write_history(&entries)?;
+    }
+    Ok(())
+}
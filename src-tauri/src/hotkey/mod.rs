@@ -15,10 +15,23 @@ const VALID_SHORTCUTS: &[&str] = &[
     "RightCommand",
 ];
 
+const VALID_TTS_SHORTCUTS: &[&str] = &[
+    "Alt+Shift+Space",
+    "Ctrl+Shift+Space",
+    "Super+Alt+Space",
+];
+
+const VALID_TRANSLATE_SHORTCUTS: &[&str] = &[
+    "Ctrl+Alt+Space",
+    "Super+Ctrl+Space",
+];
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum HotkeyAction {
     ToggleStt,
     ToggleTts,
+    /// Like `ToggleStt`, but the session is translated to English.
+    TranslateStt,
 }
 
 pub fn handle_hotkey(
@@ -28,10 +41,13 @@ pub fn handle_hotkey(
 ) -> Result<()> {
     match action {
         HotkeyAction::ToggleStt => {
-            handle_stt_shortcut(app_handle, shortcut_state)?;
+            handle_stt_shortcut(app_handle, shortcut_state, false)?;
+        }
+        HotkeyAction::TranslateStt => {
+            handle_stt_shortcut(app_handle, shortcut_state, true)?;
         }
         HotkeyAction::ToggleTts => {
-            tracing::warn!("TTS hotkey not yet implemented (Phase 6)");
+            handle_tts_shortcut(app_handle, shortcut_state)?;
         }
     }
     Ok(())
@@ -43,6 +59,11 @@ pub fn shortcut_display_label(shortcut: &str) -> &'static str {
         "Ctrl+Space" => "\u{2303}Space",
         "Super+Shift+Space" => "\u{2318}\u{21E7}Space",
         "RightCommand" => "Right \u{2318}",
+        "Alt+Shift+Space" => "\u{2325}\u{21E7}Space",
+        "Ctrl+Shift+Space" => "\u{2303}\u{21E7}Space",
+        "Super+Alt+Space" => "\u{2318}\u{2325}Space",
+        "Ctrl+Alt+Space" => "\u{2303}\u{2325}Space",
+        "Super+Ctrl+Space" => "\u{2318}\u{2303}Space",
         _ => "\u{2325}Space",
     }
 }
@@ -91,6 +112,98 @@ pub fn unregister_stt_shortcut(app_handle: &AppHandle, shortcut: &str) -> Result
     Ok(())
 }
 
+pub fn register_tts_shortcut(app_handle: &AppHandle, shortcut: &str) -> Result<()> {
+    use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+    let parsed: Shortcut = shortcut
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid shortcut '{}': {}", shortcut, e))?;
+    let app_clone = app_handle.clone();
+    app_handle
+        .global_shortcut()
+        .on_shortcut(parsed, move |_app, _shortcut, event| {
+            if let Err(e) = handle_hotkey(&app_clone, HotkeyAction::ToggleTts, event.state) {
+                tracing::error!("Hotkey error: {}", e);
+            }
+        })?;
+    tracing::info!("Registered TTS shortcut: {}", shortcut);
+    Ok(())
+}
+
+pub fn unregister_tts_shortcut(app_handle: &AppHandle, shortcut: &str) -> Result<()> {
+    use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+    if let Ok(parsed) = shortcut.parse::<Shortcut>() {
+        app_handle.global_shortcut().unregister(parsed)?;
+    }
+    tracing::info!("Unregistered TTS shortcut: {}", shortcut);
+    Ok(())
+}
+
+pub fn register_translate_shortcut(app_handle: &AppHandle, shortcut: &str) -> Result<()> {
+    use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+    let parsed: Shortcut = shortcut
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid shortcut '{}': {}", shortcut, e))?;
+    let app_clone = app_handle.clone();
+    app_handle
+        .global_shortcut()
+        .on_shortcut(parsed, move |_app, _shortcut, event| {
+            if let Err(e) = handle_hotkey(&app_clone, HotkeyAction::TranslateStt, event.state) {
+                tracing::error!("Hotkey error: {}", e);
+            }
+        })?;
+    tracing::info!("Registered translate shortcut: {}", shortcut);
+    Ok(())
+}
+
+pub fn unregister_translate_shortcut(app_handle: &AppHandle, shortcut: &str) -> Result<()> {
+    use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+    if let Ok(parsed) = shortcut.parse::<Shortcut>() {
+        app_handle.global_shortcut().unregister(parsed)?;
+    }
+    tracing::info!("Unregistered translate shortcut: {}", shortcut);
+    Ok(())
+}
+
+/// Sets or clears (`None`) the translate-to-English shortcut.
+pub fn update_translate_shortcut(app_handle: &AppHandle, new_shortcut: Option<&str>) -> Result<()> {
+    if let Some(shortcut) = new_shortcut {
+        if !VALID_TRANSLATE_SHORTCUTS.contains(&shortcut) {
+            anyhow::bail!("Invalid shortcut: {}", shortcut);
+        }
+    }
+
+    let state = app_handle.state::<crate::state::AppState>();
+    let old_shortcut = state.settings.lock().unwrap().shortcuts.translate.clone();
+
+    if old_shortcut.as_deref() == new_shortcut {
+        return Ok(());
+    }
+
+    if let Some(ref old) = old_shortcut {
+        if let Err(e) = unregister_translate_shortcut(app_handle, old) {
+            tracing::warn!("Failed to unregister old translate shortcut '{}': {}", old, e);
+        }
+    }
+
+    if let Some(shortcut) = new_shortcut {
+        if let Err(e) = register_translate_shortcut(app_handle, shortcut) {
+            tracing::error!(
+                "Failed to register new translate shortcut '{}': {}. Rolling back.",
+                shortcut,
+                e
+            );
+            if let Some(ref old) = old_shortcut {
+                let _ = register_translate_shortcut(app_handle, old);
+            }
+            anyhow::bail!("Failed to register shortcut '{}': {}", shortcut, e);
+        }
+    }
+
+    state.settings.lock().unwrap().shortcuts.translate = new_shortcut.map(|s| s.to_string());
+    crate::persistence::save_settings(app_handle);
+    Ok(())
+}
+
 fn update_tray_shortcut_label(app_handle: &AppHandle, shortcut: &str) {
     let state = app_handle.state::<crate::state::AppState>();
     let guard = state.tray_stt_shortcut_item.lock().unwrap();
@@ -147,6 +260,62 @@ pub fn update_stt_shortcut(app_handle: &AppHandle, new_shortcut: &str) -> Result
     Ok(())
 }
 
+fn update_tray_tts_shortcut_label(app_handle: &AppHandle, shortcut: &str) {
+    let state = app_handle.state::<crate::state::AppState>();
+    let guard = state.tray_tts_shortcut_item.lock().unwrap();
+    if let Some(ref item) = *guard {
+        let label = format!("  Shortcut: {}", shortcut_display_label(shortcut));
+        let _ = item.set_text(label);
+    }
+}
+
+pub fn update_tts_shortcut(app_handle: &AppHandle, new_shortcut: &str) -> Result<()> {
+    if !VALID_TTS_SHORTCUTS.contains(&new_shortcut) {
+        anyhow::bail!("Invalid shortcut: {}", new_shortcut);
+    }
+
+    let state = app_handle.state::<crate::state::AppState>();
+    let old_shortcut = state.settings.lock().unwrap().shortcuts.tts.clone();
+
+    if old_shortcut == new_shortcut {
+        return Ok(());
+    }
+
+    // Unregister the old shortcut
+    if let Err(e) = unregister_tts_shortcut(app_handle, &old_shortcut) {
+        tracing::warn!(
+            "Failed to unregister old TTS shortcut '{}': {}",
+            old_shortcut,
+            e
+        );
+    }
+
+    // Register the new shortcut
+    if let Err(e) = register_tts_shortcut(app_handle, new_shortcut) {
+        tracing::error!(
+            "Failed to register new TTS shortcut '{}': {}. Rolling back.",
+            new_shortcut,
+            e
+        );
+        // Rollback: re-register old shortcut
+        let _ = register_tts_shortcut(app_handle, &old_shortcut);
+        anyhow::bail!("Failed to register shortcut '{}': {}", new_shortcut, e);
+    }
+
+    // Update settings and tray label
+    state.settings.lock().unwrap().shortcuts.tts = new_shortcut.to_string();
+    crate::persistence::save_settings(app_handle);
+    update_tray_tts_shortcut_label(app_handle, new_shortcut);
+
+    let label = shortcut_display_label(new_shortcut);
+    let _ = app_handle.emit(
+        "tts-shortcut-changed",
+        serde_json::json!({ "label": label, "shortcut": new_shortcut }),
+    );
+
+    Ok(())
+}
+
 struct SoundPaths {
     start: PathBuf,
     stop: PathBuf,
@@ -184,7 +353,7 @@ fn pause_system_media() {
     mc.pause_if_playing();
 }
 
-fn play_feedback_sound(app_handle: &AppHandle, sound: &str) {
+pub(crate) fn play_feedback_sound(app_handle: &AppHandle, sound: &str) {
     let state = app_handle.state::<crate::state::AppState>();
     if !state.settings.lock().unwrap().general.sound_feedback {
         return;
@@ -200,7 +369,17 @@ fn play_feedback_sound(app_handle: &AppHandle, sound: &str) {
     });
 }
 
-fn handle_stt_shortcut(app_handle: &AppHandle, shortcut_state: ShortcutState) -> Result<()> {
+fn start_recording(app_handle: &AppHandle, translate: bool) -> Result<()> {
+    if translate {
+        crate::commands::stt::do_start_recording_session(app_handle, true)
+    } else {
+        crate::commands::stt::do_start_recording(app_handle)
+    }
+}
+
+/// `translate` is set for the translate shortcut, which starts English sessions and
+/// otherwise behaves like the dictation shortcut.
+fn handle_stt_shortcut(app_handle: &AppHandle, shortcut_state: ShortcutState, translate: bool) -> Result<()> {
     let state = app_handle.state::<crate::state::AppState>();
     let recording_mode = state.settings.lock().unwrap().stt.recording_mode.clone();
     let current_status = state.status.lock().unwrap().clone();
@@ -214,7 +393,7 @@ fn handle_stt_shortcut(app_handle: &AppHandle, shortcut_state: ShortcutState) ->
                 crate::state::AppStatus::Idle => {
                     pause_system_media();
                     play_feedback_sound(app_handle, "start");
-                    crate::commands::stt::do_start_recording(app_handle)?;
+                    start_recording(app_handle, translate)?;
                 }
                 crate::state::AppStatus::Recording | crate::state::AppStatus::Loading => {
                     play_feedback_sound(app_handle, "stop");
@@ -230,7 +409,7 @@ fn handle_stt_shortcut(app_handle: &AppHandle, shortcut_state: ShortcutState) ->
                 if current_status == crate::state::AppStatus::Idle {
                     pause_system_media();
                     play_feedback_sound(app_handle, "start");
-                    crate::commands::stt::do_start_recording(app_handle)?;
+                    start_recording(app_handle, translate)?;
                 }
             }
             ShortcutState::Released => {
@@ -242,6 +421,58 @@ fn handle_stt_shortcut(app_handle: &AppHandle, shortcut_state: ShortcutState) ->
                 }
             }
         },
+        RecordingMode::VoiceActivated => {
+            if shortcut_state == ShortcutState::Released {
+                return Ok(());
+            }
+            if current_status == crate::state::AppStatus::Idle {
+                let translate = translate || state.settings.lock().unwrap().stt.translate_to_english;
+                play_feedback_sound(app_handle, "start");
+                crate::commands::voice::do_arm_voice_activation(app_handle, translate)?;
+            } else if crate::commands::voice::is_armed(app_handle) {
+                play_feedback_sound(app_handle, "stop");
+                // Disarming waits for an utterance in progress to be transcribed
+                let app_handle = app_handle.clone();
+                std::thread::spawn(move || {
+                    if let Err(e) = crate::commands::voice::do_disarm_voice_activation(&app_handle) {
+                        tracing::error!("Error disarming voice activation: {}", e);
+                    }
+                });
+            } else {
+                tracing::warn!("Cannot arm voice activation in current state: {:?}", current_status);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the current selection aloud, or stops speaking if a TTS session is running.
+fn handle_tts_shortcut(app_handle: &AppHandle, shortcut_state: ShortcutState) -> Result<()> {
+    if shortcut_state == ShortcutState::Released {
+        return Ok(());
+    }
+
+    let state = app_handle.state::<crate::state::AppState>();
+    let current_status = state.status.lock().unwrap().clone();
+
+    match current_status {
+        crate::state::AppStatus::Idle => {
+            // Reading the selection may simulate a copy and wait for the clipboard,
+            // so keep it off the shortcut handler thread
+            let app_handle = app_handle.clone();
+            std::thread::spawn(move || {
+                if let Err(e) = crate::commands::tts::do_speak_selected_text(&app_handle) {
+                    tracing::error!("Error reading selection aloud: {}", e);
+                }
+            });
+        }
+        crate::state::AppStatus::Synthesizing | crate::state::AppStatus::Playing => {
+            crate::commands::tts::do_stop_speaking(app_handle)?;
+        }
+        _ => {
+            tracing::warn!("Cannot toggle TTS in current state: {:?}", current_status);
+        }
     }
 
     Ok(())
@@ -1,6 +1,8 @@
 mod audio;
 mod commands;
 mod engine;
+mod export;
+mod history;
 mod hotkey;
 mod hub;
 mod persistence;
@@ -30,6 +32,13 @@ pub fn run() {
             commands::stt::start_recording,
             commands::stt::stop_recording,
             commands::stt::get_status,
+            commands::stt::get_warm_mic_status,
+            commands::voice::arm_voice_activation,
+            commands::voice::disarm_voice_activation,
+            commands::transcribe::transcribe_file,
+            commands::transcribe::cancel_file_transcription,
+            commands::export::export_transcription,
+            commands::export::export_history_entry,
             commands::models::list_installed_models,
             commands::models::get_catalog,
             commands::models::download_model,
@@ -37,13 +46,27 @@ pub fn run() {
             commands::models::cancel_download,
             commands::models::set_active_model,
             commands::models::get_active_model,
+            commands::history::list_history,
+            commands::history::search_history,
+            commands::history::delete_history_entry,
+            commands::history::clear_history,
+            commands::history::copy_history_entry,
+            commands::history::reinject_history_entry,
             commands::tts::speak_selected_text,
             commands::tts::speak_text,
             commands::tts::stop_speaking,
             commands::settings::get_settings,
             commands::settings::update_settings,
+            commands::settings::get_decoding_settings,
+            commands::settings::update_decoding_settings,
+            commands::settings::get_hotwords,
+            commands::settings::add_hotword,
+            commands::settings::remove_hotword,
             commands::settings::update_stt_shortcut,
+            commands::settings::update_translate_shortcut,
             commands::settings::get_stt_shortcut_label,
+            commands::settings::update_tts_shortcut,
+            commands::settings::get_tts_shortcut_label,
             commands::settings::check_accessibility_permission,
             commands::settings::request_accessibility_permission,
             commands::settings::get_app_version,
@@ -52,6 +75,8 @@ pub fn run() {
             commands::settings::rerun_onboarding,
             commands::settings::retry_stt_shortcut,
             commands::settings::check_microphone_permission,
+            commands::settings::list_input_devices,
+            commands::settings::set_preferred_input_devices,
             commands::settings::request_microphone_permission,
         ])
         .setup(|app| {
@@ -67,6 +92,8 @@ pub fn run() {
             // Load settings before tray construction so we can read the saved shortcut
             let loaded = persistence::load_settings(app.handle());
             let saved_shortcut = loaded.shortcuts.stt.clone();
+            let saved_tts_shortcut = loaded.shortcuts.tts.clone();
+            let saved_translate_shortcut = loaded.shortcuts.translate.clone();
             {
                 let state = app.state::<AppState>();
                 *state.settings.lock().unwrap() = loaded;
@@ -113,14 +140,37 @@ pub fn run() {
                 *state.tray_stt_shortcut_item.lock().unwrap() = Some(stt_shortcut.clone());
             }
 
-            let tts_header = MenuItem::with_id(
+            let warm_mic = MenuItem::with_id(
                 app,
-                "tts_header",
-                "Read Aloud (TTS) \u{2014} Coming Soon",
+                "warm_mic",
+                "  Warm mic: Off",
                 false,
                 None::<&str>,
             )?;
 
+            {
+                let state = app.state::<AppState>();
+                *state.tray_warm_mic_item.lock().unwrap() = Some(warm_mic.clone());
+            }
+
+            let tts_header =
+                MenuItem::with_id(app, "tts_header", "Read Aloud (TTS)", false, None::<&str>)?;
+            let tts_shortcut = MenuItem::with_id(
+                app,
+                "tts_shortcut",
+                format!(
+                    "  Shortcut: {}",
+                    hotkey::shortcut_display_label(&saved_tts_shortcut)
+                ),
+                false,
+                None::<&str>,
+            )?;
+
+            {
+                let state = app.state::<AppState>();
+                *state.tray_tts_shortcut_item.lock().unwrap() = Some(tts_shortcut.clone());
+            }
+
             let separator1 = PredefinedMenuItem::separator(app)?;
             let separator2 = PredefinedMenuItem::separator(app)?;
             let separator3 = PredefinedMenuItem::separator(app)?;
@@ -131,8 +181,10 @@ pub fn run() {
                     &stt_header,
                     &stt_model,
                     &stt_shortcut,
+                    &warm_mic,
                     &separator1,
                     &tts_header,
+                    &tts_shortcut,
                     &separator2,
                     &show_settings,
                     &manage_models,
@@ -249,6 +301,8 @@ pub fn run() {
                 }
             }
 
+            commands::stt::apply_warm_mic(app.handle());
+
             // Register the saved shortcut, falling back to Alt+Space on failure
             if let Err(e) = hotkey::register_stt_shortcut(app.handle(), &saved_shortcut) {
                 tracing::warn!(
@@ -267,6 +321,30 @@ pub fn run() {
                 }
             }
 
+            // Register the saved TTS shortcut, falling back to Alt+Shift+Space on failure
+            if let Err(e) = hotkey::register_tts_shortcut(app.handle(), &saved_tts_shortcut) {
+                tracing::warn!(
+                    "Failed to register saved TTS shortcut '{}': {}. Falling back to Alt+Shift+Space.",
+                    saved_tts_shortcut,
+                    e
+                );
+                if saved_tts_shortcut != "Alt+Shift+Space" {
+                    if let Err(e2) = hotkey::register_tts_shortcut(app.handle(), "Alt+Shift+Space") {
+                        tracing::error!("Failed to register fallback TTS shortcut Alt+Shift+Space: {}", e2);
+                    } else {
+                        let state = app.state::<AppState>();
+                        state.settings.lock().unwrap().shortcuts.tts = "Alt+Shift+Space".to_string();
+                        persistence::save_settings(app.handle());
+                    }
+                }
+            }
+
+            if let Some(ref shortcut) = saved_translate_shortcut {
+                if let Err(e) = hotkey::register_translate_shortcut(app.handle(), shortcut) {
+                    tracing::warn!("Failed to register saved translate shortcut '{}': {}", shortcut, e);
+                }
+            }
+
             if let Some(window) = app.get_webview_window("main") {
                 let w = window.clone();
                 window.on_window_event(move |event| {
@@ -0,0 +1,912 @@
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+use x11_clipboard::Clipboard;
+use x11rb::connection::{Connection, RequestConnection};
+use x11rb::protocol::xproto::{ConnectionExt as _, KEY_PRESS_EVENT, KEY_RELEASE_EVENT};
+use x11rb::protocol::xtest::{self, ConnectionExt as _};
+use x11rb::rust_connection::RustConnection;
+use x11rb::wrapper::ConnectionExt as _;
+
+use super::{TextInjector, TextSelector, MediaController};
+
+const XK_CONTROL_L: u32 = 0xffe3;
+const XK_C: u32 = 0x0063;
+const XK_V: u32 = 0x0076;
+const XK_RETURN: u32 = 0xff0d;
+const XK_TAB: u32 = 0xff09;
+const NO_SYMBOL: u32 = 0;
+
+// Linux input event codes, used by ydotool
+const KEY_LEFTCTRL: u16 = 29;
+const KEY_C: u16 = 46;
+const KEY_V: u16 = 47;
+
+const CLIPBOARD_TIMEOUT_MS: u64 = 500;
+const COPY_SETTLE_MS: u64 = 150;
+
+/// Pause between synthesized characters, so slow apps don't drop or reorder them.
+const KEYSTROKE_DELAY_MS: u64 = 2;
+/// Longer pause after each chunk of characters, letting the target app catch up.
+const KEYSTROKE_CHUNK_SIZE: usize = 64;
+const KEYSTROKE_CHUNK_PAUSE_MS: u64 = 25;
+/// Time for clients to pick up a keymap change before the remapped key is pressed.
+const KEYMAP_SETTLE_MS: u64 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DisplayBackend {
+    X11,
+    Wayland,
+    Unknown,
+}
+
+/// Wayland wins when both are present: XTest events sent to XWayland never reach
+/// native Wayland windows.
+fn detect_backend() -> DisplayBackend {
+    let session_type = std::env::var("XDG_SESSION_TYPE").unwrap_or_default();
+    if session_type == "wayland" || std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        DisplayBackend::Wayland
+    } else if session_type == "x11" || std::env::var_os("DISPLAY").is_some() {
+        DisplayBackend::X11
+    } else {
+        DisplayBackend::Unknown
+    }
+}
+
+fn command_exists(name: &str) -> bool {
+    std::env::var_os("PATH")
+        .map(|paths| std::env::split_paths(&paths).any(|dir| dir.join(name).is_file()))
+        .unwrap_or(false)
+}
+
+// --- X11 ---
+
+/// The selection owner must stay alive to serve paste requests, so the clipboard
+/// connection lives for the whole process.
+fn x11_clipboard() -> Result<&'static Mutex<Clipboard>> {
+    static CLIPBOARD: OnceLock<Option<Mutex<Clipboard>>> = OnceLock::new();
+    CLIPBOARD
+        .get_or_init(|| match Clipboard::new() {
+            Ok(c) => Some(Mutex::new(c)),
+            Err(e) => {
+                tracing::warn!("Failed to open X11 clipboard: {}", e);
+                None
+            }
+        })
+        .as_ref()
+        .context("X11 clipboard unavailable")
+}
+
+fn x11_read_selection(primary: bool) -> Result<String> {
+    let clipboard = x11_clipboard()?.lock().unwrap();
+    let atoms = &clipboard.getter.atoms;
+    let selection = if primary { atoms.primary } else { atoms.clipboard };
+    let bytes = clipboard
+        .load(
+            selection,
+            atoms.utf8_string,
+            atoms.property,
+            Duration::from_millis(CLIPBOARD_TIMEOUT_MS),
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to read X11 selection: {}", e))?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn x11_write_clipboard(text: &str) -> Result<()> {
+    let clipboard = x11_clipboard()?.lock().unwrap();
+    let atoms = &clipboard.setter.atoms;
+    clipboard
+        .store(atoms.clipboard, atoms.utf8_string, text.as_bytes())
+        .map_err(|e| anyhow::anyhow!("Failed to set X11 clipboard: {}", e))
+}
+
+/// Picks the representation to preserve: text if offered, otherwise the first
+/// MIME-typed target (images, file lists). Selection meta-targets are skipped.
+fn preferred_clipboard_type(types: &[String]) -> Option<String> {
+    const TEXT_TYPES: &[&str] = &["UTF8_STRING", "text/plain;charset=utf-8", "text/plain"];
+    TEXT_TYPES
+        .iter()
+        .find(|t| types.iter().any(|ty| ty == *t))
+        .map(|t| t.to_string())
+        .or_else(|| types.iter().find(|ty| ty.contains('/')).cloned())
+}
+
+fn x11_snapshot_clipboard() -> Result<Option<ClipboardSnapshot>> {
+    let clipboard = x11_clipboard()?.lock().unwrap();
+    let atoms = &clipboard.getter.atoms;
+    let timeout = Duration::from_millis(CLIPBOARD_TIMEOUT_MS);
+
+    let raw = clipboard
+        .load(atoms.clipboard, atoms.targets, atoms.property, timeout)
+        .map_err(|e| anyhow::anyhow!("Failed to read X11 clipboard targets: {}", e))?;
+    let conn = &clipboard.getter.connection;
+    let mut types = Vec::new();
+    for chunk in raw.chunks_exact(4) {
+        let atom = u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        if let Ok(reply) = conn.get_atom_name(atom)?.reply() {
+            types.push(String::from_utf8_lossy(&reply.name).into_owned());
+        }
+    }
+
+    let Some(mime) = preferred_clipboard_type(&types) else {
+        return Ok(None);
+    };
+    let target = clipboard.getter.get_atom(&mime)
+        .map_err(|e| anyhow::anyhow!("Failed to intern atom {}: {}", mime, e))?;
+    let data = clipboard
+        .load(atoms.clipboard, target, atoms.property, timeout)
+        .map_err(|e| anyhow::anyhow!("Failed to read X11 clipboard as {}: {}", mime, e))?;
+    Ok(Some(ClipboardSnapshot { mime, data }))
+}
+
+fn x11_restore_clipboard(snapshot: &ClipboardSnapshot) -> Result<()> {
+    let clipboard = x11_clipboard()?.lock().unwrap();
+    let target = clipboard.setter.get_atom(&snapshot.mime)
+        .map_err(|e| anyhow::anyhow!("Failed to intern atom {}: {}", snapshot.mime, e))?;
+    clipboard
+        .store(clipboard.setter.atoms.clipboard, target, snapshot.data.clone())
+        .map_err(|e| anyhow::anyhow!("Failed to restore X11 clipboard: {}", e))
+}
+
+fn x11_has_xtest() -> bool {
+    match x11rb::connect(None) {
+        Ok((conn, _)) => conn
+            .extension_information(xtest::X11_EXTENSION_NAME)
+            .ok()
+            .flatten()
+            .is_some(),
+        Err(_) => false,
+    }
+}
+
+fn keycode_for_keysym(conn: &RustConnection, keysym: u32) -> Result<u8> {
+    let setup = conn.setup();
+    let min = setup.min_keycode;
+    let count = setup.max_keycode - min + 1;
+    let mapping = conn.get_keyboard_mapping(min, count)?.reply()?;
+    let per = mapping.keysyms_per_keycode as usize;
+    mapping
+        .keysyms
+        .chunks(per.max(1))
+        .position(|syms| syms.contains(&keysym))
+        .map(|i| min + i as u8)
+        .ok_or_else(|| anyhow::anyhow!("No keycode for keysym {:#x}", keysym))
+}
+
+/// Presses Ctrl+<key> through XTest.
+fn x11_simulate_ctrl_key(keysym: u32) -> Result<()> {
+    let (conn, screen) = x11rb::connect(None).context("Failed to connect to X server")?;
+    if conn.extension_information(xtest::X11_EXTENSION_NAME)?.is_none() {
+        anyhow::bail!("XTest extension not available");
+    }
+    let root = conn.setup().roots[screen].root;
+    let ctrl = keycode_for_keysym(&conn, XK_CONTROL_L)?;
+    let key = keycode_for_keysym(&conn, keysym)?;
+
+    for (event, code) in [
+        (KEY_PRESS_EVENT, ctrl),
+        (KEY_PRESS_EVENT, key),
+        (KEY_RELEASE_EVENT, key),
+        (KEY_RELEASE_EVENT, ctrl),
+    ] {
+        conn.xtest_fake_input(event, code, x11rb::CURRENT_TIME, root, 0, 0, 0)?;
+    }
+    conn.sync()?;
+    Ok(())
+}
+
+/// X keysym for a character: Latin-1 maps directly, everything else uses the
+/// Unicode keysym range.
+fn keysym_for_char(ch: char) -> u32 {
+    let cp = ch as u32;
+    if (0x20..=0x7e).contains(&cp) || (0xa0..=0xff).contains(&cp) {
+        cp
+    } else {
+        0x0100_0000 | cp
+    }
+}
+
+/// A keycode with no keysyms bound, which we temporarily remap to each character.
+fn find_scratch_keycode(conn: &RustConnection) -> Result<(u8, u8)> {
+    let setup = conn.setup();
+    let min = setup.min_keycode;
+    let count = setup.max_keycode - min + 1;
+    let mapping = conn.get_keyboard_mapping(min, count)?.reply()?;
+    let per = mapping.keysyms_per_keycode.max(1);
+    mapping
+        .keysyms
+        .chunks(per as usize)
+        .rposition(|syms| syms.iter().all(|&s| s == NO_SYMBOL))
+        .map(|i| (min + i as u8, per))
+        .ok_or_else(|| anyhow::anyhow!("No free keycode available for keystroke injection"))
+}
+
+fn x11_tap_key(conn: &RustConnection, root: u32, keycode: u8) -> Result<()> {
+    conn.xtest_fake_input(KEY_PRESS_EVENT, keycode, x11rb::CURRENT_TIME, root, 0, 0, 0)?;
+    conn.xtest_fake_input(KEY_RELEASE_EVENT, keycode, x11rb::CURRENT_TIME, root, 0, 0, 0)?;
+    conn.sync()?;
+    Ok(())
+}
+
+/// Types `text` through XTest by binding each character to a spare keycode,
+/// the same trick xdotool uses for characters missing from the layout.
+fn x11_type_text(text: &str) -> Result<()> {
+    let (conn, screen) = x11rb::connect(None).context("Failed to connect to X server")?;
+    if conn.extension_information(xtest::X11_EXTENSION_NAME)?.is_none() {
+        anyhow::bail!("XTest extension not available");
+    }
+    let root = conn.setup().roots[screen].root;
+    let return_key = keycode_for_keysym(&conn, XK_RETURN)?;
+    let tab_key = keycode_for_keysym(&conn, XK_TAB)?;
+    let (scratch, per) = find_scratch_keycode(&conn)?;
+
+    let result = (|| -> Result<()> {
+        for (i, ch) in text.chars().enumerate() {
+            match ch {
+                '\r' => continue,
+                '\n' => x11_tap_key(&conn, root, return_key)?,
+                '\t' => x11_tap_key(&conn, root, tab_key)?,
+                _ => {
+                    let keysyms = vec![keysym_for_char(ch); per as usize];
+                    conn.change_keyboard_mapping(1, scratch, per, &keysyms)?;
+                    conn.sync()?;
+                    std::thread::sleep(Duration::from_millis(KEYMAP_SETTLE_MS));
+                    x11_tap_key(&conn, root, scratch)?;
+                }
+            }
+            if (i + 1) % KEYSTROKE_CHUNK_SIZE == 0 {
+                std::thread::sleep(Duration::from_millis(KEYSTROKE_CHUNK_PAUSE_MS));
+            } else {
+                std::thread::sleep(Duration::from_millis(KEYSTROKE_DELAY_MS));
+            }
+        }
+        Ok(())
+    })();
+
+    // Give the last key time to be processed before unbinding the scratch keycode
+    std::thread::sleep(Duration::from_millis(KEYSTROKE_CHUNK_PAUSE_MS));
+    let cleared = vec![NO_SYMBOL; per as usize];
+    let reset = conn.change_keyboard_mapping(1, scratch, per, &cleared)
+        .map_err(anyhow::Error::from)
+        .and_then(|_| Ok(conn.sync()?));
+    if let Err(e) = reset {
+        tracing::warn!("Failed to reset scratch keycode {}: {}", scratch, e);
+    }
+    result
+}
+
+// --- Wayland ---
+
+fn wayland_read_selection(primary: bool) -> Result<String> {
+    let mut cmd = Command::new("wl-paste");
+    cmd.arg("--no-newline");
+    if primary {
+        cmd.arg("--primary");
+    }
+    let output = cmd
+        .stderr(Stdio::null())
+        .output()
+        .context("Failed to run wl-paste — install wl-clipboard")?;
+    // wl-paste exits non-zero when the selection is empty
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn wayland_write_clipboard(text: &str) -> Result<()> {
+    let mut child = Command::new("wl-copy")
+        .stdin(Stdio::piped())
+        .spawn()
+        .context("Failed to run wl-copy — install wl-clipboard")?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    child.wait()?;
+    Ok(())
+}
+
+fn wayland_snapshot_clipboard() -> Result<Option<ClipboardSnapshot>> {
+    let output = Command::new("wl-paste")
+        .arg("--list-types")
+        .stderr(Stdio::null())
+        .output()
+        .context("Failed to run wl-paste — install wl-clipboard")?;
+    let types: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+        .collect();
+
+    let Some(mime) = preferred_clipboard_type(&types) else {
+        return Ok(None);
+    };
+    let output = Command::new("wl-paste")
+        .args(["--type", &mime])
+        .stderr(Stdio::null())
+        .output()?;
+    Ok(Some(ClipboardSnapshot { mime, data: output.stdout }))
+}
+
+fn wayland_restore_clipboard(snapshot: &ClipboardSnapshot) -> Result<()> {
+    let mut child = Command::new("wl-copy")
+        .args(["--type", &snapshot.mime])
+        .stdin(Stdio::piped())
+        .spawn()
+        .context("Failed to run wl-copy — install wl-clipboard")?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(&snapshot.data)?;
+    }
+    child.wait()?;
+    Ok(())
+}
+
+/// Presses Ctrl+<key> with wtype, or ydotool when the compositor lacks the
+/// virtual-keyboard protocol (GNOME).
+fn wayland_simulate_ctrl_key(key: char, input_code: u16) -> Result<()> {
+    if command_exists("wtype") {
+        let status = Command::new("wtype")
+            .args(["-M", "ctrl", &key.to_string(), "-m", "ctrl"])
+            .status()?;
+        if status.success() {
+            return Ok(());
+        }
+        tracing::warn!("wtype exited with {}, trying ydotool", status);
+    }
+    if command_exists("ydotool") {
+        let status = Command::new("ydotool")
+            .args([
+                "key",
+                &format!("{}:1", KEY_LEFTCTRL),
+                &format!("{}:1", input_code),
+                &format!("{}:0", input_code),
+                &format!("{}:0", KEY_LEFTCTRL),
+            ])
+            .status()?;
+        if status.success() {
+            return Ok(());
+        }
+        anyhow::bail!("ydotool exited with {}", status);
+    }
+    anyhow::bail!("No Wayland key injection tool found — install wtype or ydotool")
+}
+
+/// Builds wtype's argv for one chunk: text segments as plain arguments, with
+/// newlines and tabs as `-k Return`/`-k Tab`. wtype has no per-argument escape
+/// (`--` turns everything after it into text), so a segment's leading dashes
+/// are sent as `-k minus` instead.
+fn wtype_args(chunk: &[char]) -> Vec<String> {
+    fn push_text(args: &mut Vec<String>, segment: &str) {
+        let text = segment.trim_start_matches('-');
+        for _ in 0..segment.len() - text.len() {
+            args.extend(["-k".to_string(), "minus".to_string()]);
+        }
+        if !text.is_empty() {
+            args.push(text.to_string());
+        }
+    }
+
+    let mut args = vec!["-d".to_string(), KEYSTROKE_DELAY_MS.to_string()];
+    let mut segment = String::new();
+    for &ch in chunk {
+        let key = match ch {
+            '\n' => Some("Return"),
+            '\t' => Some("Tab"),
+            _ => None,
+        };
+        match key {
+            Some(key) => {
+                push_text(&mut args, &std::mem::take(&mut segment));
+                args.extend(["-k".to_string(), key.to_string()]);
+            }
+            None => segment.push(ch),
+        }
+    }
+    push_text(&mut args, &segment);
+    args
+}
+
+/// Types `text` with wtype (full Unicode) or ydotool (layout-bound), in chunks.
+fn wayland_type_text(text: &str) -> Result<()> {
+    let use_wtype = command_exists("wtype");
+    if !use_wtype && !command_exists("ydotool") {
+        anyhow::bail!("No Wayland key injection tool found — install wtype or ydotool");
+    }
+
+    let chars: Vec<char> = text.chars().filter(|&c| c != '\r').collect();
+    for chunk in chars.chunks(KEYSTROKE_CHUNK_SIZE) {
+        let status = if use_wtype {
+            let args = wtype_args(chunk);
+            Command::new("wtype").args(&args).status()?
+        } else {
+            let segment: String = chunk.iter().collect();
+            Command::new("ydotool")
+                .args(["type", "--key-delay", &KEYSTROKE_DELAY_MS.to_string(), "--", &segment])
+                .status()?
+        };
+        if !status.success() {
+            anyhow::bail!("Keystroke injection tool exited with {}", status);
+        }
+        std::thread::sleep(Duration::from_millis(KEYSTROKE_CHUNK_PAUSE_MS));
+    }
+    Ok(())
+}
+
+// --- Backend dispatch ---
+
+fn read_selection(backend: DisplayBackend, primary: bool) -> Result<String> {
+    match backend {
+        DisplayBackend::X11 => x11_read_selection(primary),
+        DisplayBackend::Wayland => wayland_read_selection(primary),
+        DisplayBackend::Unknown => anyhow::bail!("No display server detected"),
+    }
+}
+
+fn copy_to_clipboard(backend: DisplayBackend, text: &str) -> Result<()> {
+    match backend {
+        DisplayBackend::X11 => x11_write_clipboard(text),
+        DisplayBackend::Wayland => wayland_write_clipboard(text),
+        DisplayBackend::Unknown => anyhow::bail!("No display server detected"),
+    }
+}
+
+/// One representation of the clipboard contents, kept to restore after a paste.
+struct ClipboardSnapshot {
+    mime: String,
+    data: Vec<u8>,
+}
+
+fn snapshot_clipboard(backend: DisplayBackend) -> Result<Option<ClipboardSnapshot>> {
+    match backend {
+        DisplayBackend::X11 => x11_snapshot_clipboard(),
+        DisplayBackend::Wayland => wayland_snapshot_clipboard(),
+        DisplayBackend::Unknown => Ok(None),
+    }
+}
+
+fn restore_clipboard(backend: DisplayBackend, snapshot: &ClipboardSnapshot) -> Result<()> {
+    match backend {
+        DisplayBackend::X11 => x11_restore_clipboard(snapshot),
+        DisplayBackend::Wayland => wayland_restore_clipboard(snapshot),
+        DisplayBackend::Unknown => Ok(()),
+    }
+}
+
+/// Puts the snapshot back after `delay`, unless the clipboard no longer holds the
+/// pasted text (the user copied something else in the meantime).
+fn schedule_clipboard_restore(
+    backend: DisplayBackend,
+    snapshot: ClipboardSnapshot,
+    pasted: String,
+    delay: Duration,
+) {
+    std::thread::spawn(move || {
+        std::thread::sleep(delay);
+        match read_selection(backend, false) {
+            Ok(current) if current == pasted => {}
+            _ => {
+                tracing::info!("Clipboard changed since paste — not restoring previous contents");
+                return;
+            }
+        }
+        match restore_clipboard(backend, &snapshot) {
+            Ok(()) => tracing::info!("Previous clipboard contents restored ({})", snapshot.mime),
+            Err(e) => tracing::warn!("Failed to restore clipboard: {}", e),
+        }
+    });
+}
+
+fn type_text(backend: DisplayBackend, text: &str) -> Result<()> {
+    match backend {
+        DisplayBackend::X11 => x11_type_text(text),
+        DisplayBackend::Wayland => wayland_type_text(text),
+        DisplayBackend::Unknown => anyhow::bail!("No display server detected"),
+    }
+}
+
+fn simulate_ctrl_v(backend: DisplayBackend) -> Result<()> {
+    match backend {
+        DisplayBackend::X11 => x11_simulate_ctrl_key(XK_V),
+        DisplayBackend::Wayland => wayland_simulate_ctrl_key('v', KEY_V),
+        DisplayBackend::Unknown => anyhow::bail!("No display server detected"),
+    }
+}
+
+fn simulate_ctrl_c(backend: DisplayBackend) -> Result<()> {
+    match backend {
+        DisplayBackend::X11 => x11_simulate_ctrl_key(XK_C),
+        DisplayBackend::Wayland => wayland_simulate_ctrl_key('c', KEY_C),
+        DisplayBackend::Unknown => anyhow::bail!("No display server detected"),
+    }
+}
+
+fn can_inject(backend: DisplayBackend) -> bool {
+    match backend {
+        DisplayBackend::X11 => x11_has_xtest(),
+        DisplayBackend::Wayland => command_exists("wtype") || command_exists("ydotool"),
+        DisplayBackend::Unknown => false,
+    }
+}
+
+pub struct LinuxTextInjector {
+    backend: DisplayBackend,
+}
+
+impl LinuxTextInjector {
+    pub fn new() -> Self {
+        let backend = detect_backend();
+        tracing::debug!("Linux display backend: {:?}", backend);
+        Self { backend }
+    }
+}
+
+impl TextInjector for LinuxTextInjector {
+    fn inject_text(&self, text: &str) -> Result<()> {
+        tracing::info!("Typing {} chars via keystrokes ({:?})", text.chars().count(), self.backend);
+        type_text(self.backend, text)
+    }
+
+    fn inject_via_clipboard(&self, text: &str, restore_after: Option<Duration>) -> Result<()> {
+        let snapshot = match restore_after {
+            Some(_) => snapshot_clipboard(self.backend).unwrap_or_else(|e| {
+                tracing::warn!("Failed to snapshot clipboard: {}", e);
+                None
+            }),
+            None => None,
+        };
+        copy_to_clipboard(self.backend, text)?;
+        let usable = self.is_accessibility_granted();
+        tracing::info!("Key injection usable ({:?}) = {}, attempting text injection ({} chars)", self.backend, usable, text.len());
+        if usable {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            if let Err(e) = simulate_ctrl_v(self.backend) {
+                tracing::warn!("Ctrl+V simulation failed: {}. Text is in clipboard.", e);
+            } else {
+                tracing::info!("Ctrl+V simulated successfully");
+                if let (Some(snapshot), Some(delay)) = (snapshot, restore_after) {
+                    schedule_clipboard_restore(self.backend, snapshot, text.to_string(), delay);
+                }
+            }
+        } else {
+            tracing::warn!("No key injection method available — text copied to clipboard but cannot auto-paste. On Wayland, install wtype or ydotool.");
+        }
+        Ok(())
+    }
+
+    fn copy_to_clipboard(&self, text: &str) -> Result<()> {
+        copy_to_clipboard(self.backend, text)
+    }
+
+    fn is_accessibility_granted(&self) -> bool {
+        can_inject(self.backend)
+    }
+
+    fn request_accessibility(&self) -> Result<()> {
+        if self.is_accessibility_granted() {
+            return Ok(());
+        }
+        match self.backend {
+            DisplayBackend::X11 => anyhow::bail!("The X server does not provide the XTest extension"),
+            DisplayBackend::Wayland => anyhow::bail!("Install wtype or ydotool (with ydotoold running) to enable auto-paste"),
+            DisplayBackend::Unknown => anyhow::bail!("No X11 or Wayland display detected"),
+        }
+    }
+}
+
+pub struct LinuxTextSelector {
+    backend: DisplayBackend,
+}
+
+impl LinuxTextSelector {
+    pub fn new() -> Self {
+        Self { backend: detect_backend() }
+    }
+
+    /// Fallback for apps that don't publish PRIMARY: simulate Ctrl+C and read the
+    /// clipboard, then put the previous contents back, even if the copy failed.
+    fn selected_text_via_copy(&self) -> Result<Option<String>> {
+        let snapshot = snapshot_clipboard(self.backend).unwrap_or_else(|e| {
+            tracing::warn!("Failed to snapshot clipboard: {}", e);
+            None
+        });
+
+        let copied = (|| -> Result<String> {
+            // Clear first so an unchanged clipboard isn't mistaken for the selection
+            copy_to_clipboard(self.backend, "")?;
+            simulate_ctrl_c(self.backend)?;
+            std::thread::sleep(std::time::Duration::from_millis(COPY_SETTLE_MS));
+            read_selection(self.backend, false)
+        })();
+
+        let restored = match &snapshot {
+            Some(snapshot) => restore_clipboard(self.backend, snapshot),
+            None => copy_to_clipboard(self.backend, ""),
+        };
+        if let Err(e) = restored {
+            tracing::warn!("Failed to restore clipboard after reading selection: {}", e);
+        }
+
+        let copied = copied?;
+        Ok(if copied.is_empty() { None } else { Some(copied) })
+    }
+}
+
+impl TextSelector for LinuxTextSelector {
+    fn get_selected_text(&self) -> Result<Option<String>> {
+        match read_selection(self.backend, true) {
+            Ok(text) if !text.trim().is_empty() => {
+                tracing::info!("Selected text read from PRIMARY ({} chars)", text.len());
+                return Ok(Some(text));
+            }
+            Ok(_) => {}
+            Err(e) => tracing::debug!("PRIMARY selection unavailable: {}", e),
+        }
+
+        if !can_inject(self.backend) {
+            return Ok(None);
+        }
+        let text = self.selected_text_via_copy()?;
+        tracing::info!("Selected text read via Ctrl+C fallback ({} chars)", text.as_ref().map_or(0, |t| t.len()));
+        Ok(text)
+    }
+
+    fn is_supported(&self) -> bool {
+        self.backend != DisplayBackend::Unknown
+    }
+}
+
+const MPRIS_PREFIX: &str = "org.mpris.MediaPlayer2.";
+const MPRIS_PATH: &str = "/org/mpris/MediaPlayer2";
+const MPRIS_PLAYER_INTERFACE: &str = "org.mpris.MediaPlayer2.Player";
+
+/// MPRIS over the D-Bus session bus. Only players that were actually Playing get
+/// paused, and `resume()` plays exactly those again.
+pub struct LinuxMediaController {
+    bus: OnceLock<Option<zbus::blocking::Connection>>,
+    paused_players: Mutex<Vec<String>>,
+}
+
+static MEDIA_CONTROLLER: OnceLock<LinuxMediaController> = OnceLock::new();
+
+impl LinuxMediaController {
+    pub fn instance() -> &'static Self {
+        MEDIA_CONTROLLER.get_or_init(|| LinuxMediaController {
+            bus: OnceLock::new(),
+            paused_players: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// The session bus, connected on first use.
+    fn bus(&self) -> Option<&zbus::blocking::Connection> {
+        self.bus.get_or_init(|| match zbus::blocking::Connection::session() {
+            Ok(conn) => Some(conn),
+            Err(e) => {
+                tracing::warn!("Failed to connect to D-Bus session bus: {}", e);
+                None
+            }
+        }).as_ref()
+    }
+
+    fn list_players(conn: &zbus::blocking::Connection) -> Result<Vec<String>> {
+        let dbus = zbus::blocking::fdo::DBusProxy::new(conn)?;
+        Ok(dbus.list_names()?
+            .into_iter()
+            .map(|name| name.to_string())
+            .filter(|name| name.starts_with(MPRIS_PREFIX))
+            .collect())
+    }
+
+    fn player_proxy<'a>(conn: &zbus::blocking::Connection, name: &'a str) -> Result<zbus::blocking::Proxy<'a>> {
+        Ok(zbus::blocking::Proxy::new(conn, name, MPRIS_PATH, MPRIS_PLAYER_INTERFACE)?)
+    }
+
+    fn pause_playing_players(conn: &zbus::blocking::Connection) -> Result<Vec<String>> {
+        let mut paused = Vec::new();
+        for name in Self::list_players(conn)? {
+            let proxy = match Self::player_proxy(conn, &name) {
+                Ok(p) => p,
+                Err(e) => {
+                    tracing::debug!("MPRIS: skipping {}: {}", name, e);
+                    continue;
+                }
+            };
+            let status: String = match proxy.get_property("PlaybackStatus") {
+                Ok(s) => s,
+                Err(e) => {
+                    tracing::debug!("MPRIS: no PlaybackStatus for {}: {}", name, e);
+                    continue;
+                }
+            };
+            if status != "Playing" {
+                continue;
+            }
+            match proxy.call_method("Pause", &()) {
+                Ok(_) => {
+                    tracing::info!("MPRIS: paused {}", name);
+                    paused.push(name.clone());
+                }
+                Err(e) => tracing::warn!("MPRIS: failed to pause {}: {}", name, e),
+            }
+        }
+        Ok(paused)
+    }
+}
+
+impl MediaController for LinuxMediaController {
+    fn pause_if_playing(&self) {
+        let Some(conn) = self.bus() else {
+            return;
+        };
+        match Self::pause_playing_players(conn) {
+            Ok(players) => {
+                let mut paused = self.paused_players.lock().unwrap();
+                for player in players {
+                    if !paused.contains(&player) {
+                        paused.push(player);
+                    }
+                }
+            }
+            Err(e) => tracing::warn!("MPRIS: failed to list players: {}", e),
+        }
+    }
+
+    fn resume(&self) {
+        let players = std::mem::take(&mut *self.paused_players.lock().unwrap());
+        if players.is_empty() {
+            return;
+        }
+        let Some(conn) = self.bus() else {
+            return;
+        };
+        for name in players {
+            let result = Self::player_proxy(conn, &name)
+                .and_then(|proxy| Ok(proxy.call_method("Play", &())?));
+            match result {
+                Ok(_) => tracing::info!("MPRIS: resumed {}", name),
+                // The player may have quit while we were recording
+                Err(e) => tracing::warn!("MPRIS: failed to resume {}: {}", name, e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::process::Child;
+    use std::sync::Arc;
+
+    /// A private bus, so the test never touches the user's real players.
+    struct PrivateBus {
+        daemon: Child,
+        address: String,
+    }
+
+    impl PrivateBus {
+        fn start() -> Option<Self> {
+            let mut daemon = match Command::new("dbus-daemon")
+                .args(["--session", "--nofork", "--print-address"])
+                .stdout(Stdio::piped())
+                .spawn()
+            {
+                Ok(child) => child,
+                Err(e) => {
+                    eprintln!("skipping: dbus-daemon not available: {}", e);
+                    return None;
+                }
+            };
+            let mut address = String::new();
+            BufReader::new(daemon.stdout.take()?).read_line(&mut address).ok()?;
+            Some(PrivateBus {
+                daemon,
+                address: address.trim().to_string(),
+            })
+        }
+
+        fn connect(&self) -> zbus::blocking::Connection {
+            zbus::blocking::connection::Builder::address(self.address.as_str())
+                .unwrap()
+                .build()
+                .unwrap()
+        }
+    }
+
+    impl Drop for PrivateBus {
+        fn drop(&mut self) {
+            let _ = self.daemon.kill();
+            let _ = self.daemon.wait();
+        }
+    }
+
+    struct MockPlayer {
+        status: Arc<Mutex<String>>,
+    }
+
+    #[zbus::interface(name = "org.mpris.MediaPlayer2.Player")]
+    impl MockPlayer {
+        fn pause(&self) {
+            *self.status.lock().unwrap() = "Paused".to_string();
+        }
+
+        fn play(&self) {
+            *self.status.lock().unwrap() = "Playing".to_string();
+        }
+
+        #[zbus(property)]
+        fn playback_status(&self) -> String {
+            self.status.lock().unwrap().clone()
+        }
+    }
+
+    /// Serves a mock player under `org.mpris.MediaPlayer2.<suffix>`; it lives as
+    /// long as the returned connection.
+    fn serve_player(
+        bus: &PrivateBus,
+        suffix: &str,
+        status: &str,
+    ) -> (zbus::blocking::Connection, Arc<Mutex<String>>) {
+        let status = Arc::new(Mutex::new(status.to_string()));
+        let conn = zbus::blocking::connection::Builder::address(bus.address.as_str())
+            .unwrap()
+            .name(format!("{}{}", MPRIS_PREFIX, suffix))
+            .unwrap()
+            .serve_at(MPRIS_PATH, MockPlayer { status: status.clone() })
+            .unwrap()
+            .build()
+            .unwrap();
+        (conn, status)
+    }
+
+    fn wtype_argv(text: &str) -> Vec<String> {
+        let chars: Vec<char> = text.chars().collect();
+        wtype_args(&chars)[2..].to_vec()
+    }
+
+    #[test]
+    fn wtype_args_press_keys_between_text_segments() {
+        assert_eq!(
+            wtype_argv("foo\nbar\tbaz"),
+            ["foo", "-k", "Return", "bar", "-k", "Tab", "baz"]
+        );
+        assert_eq!(wtype_argv("\n\n"), ["-k", "Return", "-k", "Return"]);
+    }
+
+    #[test]
+    fn wtype_args_never_pass_text_starting_with_a_dash() {
+        assert_eq!(
+            wtype_argv("--x\n-"),
+            ["-k", "minus", "-k", "minus", "x", "-k", "Return", "-k", "minus"]
+        );
+        assert_eq!(wtype_argv("a -b"), ["a -b"]);
+    }
+
+    #[test]
+    fn pauses_and_resumes_only_playing_players() {
+        let Some(bus) = PrivateBus::start() else {
+            return;
+        };
+        let (_playing_conn, playing) = serve_player(&bus, "mockPlaying", "Playing");
+        let (_paused_conn, paused) = serve_player(&bus, "mockPaused", "Paused");
+        let (_stopped_conn, stopped) = serve_player(&bus, "mockStopped", "Stopped");
+
+        let controller = LinuxMediaController {
+            bus: OnceLock::from(Some(bus.connect())),
+            paused_players: Mutex::new(Vec::new()),
+        };
+
+        controller.pause_if_playing();
+        assert_eq!(*playing.lock().unwrap(), "Paused");
+        assert_eq!(*paused.lock().unwrap(), "Paused");
+        assert_eq!(*stopped.lock().unwrap(), "Stopped");
+        assert_eq!(
+            *controller.paused_players.lock().unwrap(),
+            vec![format!("{}mockPlaying", MPRIS_PREFIX)]
+        );
+
+        controller.resume();
+        assert_eq!(*playing.lock().unwrap(), "Playing");
+        assert_eq!(*paused.lock().unwrap(), "Paused");
+        assert_eq!(*stopped.lock().unwrap(), "Stopped");
+        assert!(controller.paused_players.lock().unwrap().is_empty());
+    }
+}
@@ -12,23 +12,70 @@ extern "C" {
     ) -> CGEventRef;
     fn CGEventSetFlags(event: CGEventRef, flags: u64);
     fn CGEventPost(tap: u32, event: CGEventRef);
+    fn CGEventKeyboardSetUnicodeString(event: CGEventRef, length: usize, string: *const u16);
     fn CFRelease(cf: *const std::ffi::c_void);
+    fn AXUIElementCreateSystemWide() -> AXUIElementRef;
+    fn AXUIElementCopyAttributeValue(
+        element: AXUIElementRef,
+        attribute: CFStringRef,
+        value: *mut *const std::ffi::c_void,
+    ) -> i32;
+    fn CFStringCreateWithBytes(
+        alloc: *const std::ffi::c_void,
+        bytes: *const u8,
+        num_bytes: isize,
+        encoding: u32,
+        is_external_representation: bool,
+    ) -> CFStringRef;
+    fn CFStringGetLength(string: CFStringRef) -> isize;
+    fn CFStringGetMaximumSizeForEncoding(length: isize, encoding: u32) -> isize;
+    fn CFStringGetCString(
+        string: CFStringRef,
+        buffer: *mut std::ffi::c_char,
+        buffer_size: isize,
+        encoding: u32,
+    ) -> bool;
+    fn CFGetTypeID(cf: *const std::ffi::c_void) -> usize;
+    fn CFStringGetTypeID() -> usize;
 }
 
+type AXUIElementRef = *const std::ffi::c_void;
+type CFStringRef = *const std::ffi::c_void;
+
 const K_VK_V: u16 = 9;
+const K_VK_C: u16 = 8;
+const K_CF_STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+const K_AX_ERROR_SUCCESS: i32 = 0;
+const COPY_SETTLE_MS: u64 = 150;
 const K_CG_EVENT_FLAG_MASK_COMMAND: u64 = 1 << 20;
 const K_CG_HID_EVENT_TAP: u32 = 0;
+const K_VK_RETURN: u16 = 36;
+const K_VK_TAB: u16 = 48;
+
+/// Pause between synthesized characters, so slow apps don't drop or reorder them.
+const KEYSTROKE_DELAY_MS: u64 = 2;
+/// Longer pause after each chunk of characters, letting the target app catch up.
+const KEYSTROKE_CHUNK_SIZE: usize = 64;
+const KEYSTROKE_CHUNK_PAUSE_MS: u64 = 25;
 
 fn simulate_cmd_v() -> Result<()> {
+    simulate_cmd_key(K_VK_V)
+}
+
+fn simulate_cmd_c() -> Result<()> {
+    simulate_cmd_key(K_VK_C)
+}
+
+fn simulate_cmd_key(virtual_key: u16) -> Result<()> {
     unsafe {
-        let key_down = CGEventCreateKeyboardEvent(std::ptr::null(), K_VK_V, true);
+        let key_down = CGEventCreateKeyboardEvent(std::ptr::null(), virtual_key, true);
         if key_down.is_null() {
-            anyhow::bail!("Failed to create CGEvent for Cmd+V — grant Accessibility permission");
+            anyhow::bail!("Failed to create CGEvent for Cmd+key {} — grant Accessibility permission", virtual_key);
         }
         CGEventSetFlags(key_down, K_CG_EVENT_FLAG_MASK_COMMAND);
         CGEventPost(K_CG_HID_EVENT_TAP, key_down);
 
-        let key_up = CGEventCreateKeyboardEvent(std::ptr::null(), K_VK_V, false);
+        let key_up = CGEventCreateKeyboardEvent(std::ptr::null(), virtual_key, false);
         if !key_up.is_null() {
             CGEventSetFlags(key_up, K_CG_EVENT_FLAG_MASK_COMMAND);
             CGEventPost(K_CG_HID_EVENT_TAP, key_up);
@@ -40,6 +87,47 @@ fn simulate_cmd_v() -> Result<()> {
     Ok(())
 }
 
+/// Posts a key down/up pair. `unicode` overrides the character the key produces,
+/// which lets us type any character regardless of the keyboard layout.
+fn post_key(virtual_key: u16, unicode: Option<&[u16]>) -> Result<()> {
+    unsafe {
+        for key_down in [true, false] {
+            let event = CGEventCreateKeyboardEvent(std::ptr::null(), virtual_key, key_down);
+            if event.is_null() {
+                anyhow::bail!("Failed to create CGEvent for keystroke — grant Accessibility permission");
+            }
+            // Clear modifiers so a still-held shortcut key doesn't alter the character
+            CGEventSetFlags(event, 0);
+            if let Some(units) = unicode {
+                CGEventKeyboardSetUnicodeString(event, units.len(), units.as_ptr());
+            }
+            CGEventPost(K_CG_HID_EVENT_TAP, event);
+            CFRelease(event as *const _);
+        }
+    }
+    Ok(())
+}
+
+/// Types `text` one character at a time. Newlines and tabs are sent as the Return
+/// and Tab keys so editors treat them as real key presses.
+fn type_text(text: &str) -> Result<()> {
+    let mut buf = [0u16; 2];
+    for (i, ch) in text.chars().enumerate() {
+        match ch {
+            '\r' => continue,
+            '\n' => post_key(K_VK_RETURN, None)?,
+            '\t' => post_key(K_VK_TAB, None)?,
+            _ => post_key(0, Some(ch.encode_utf16(&mut buf)))?,
+        }
+        if (i + 1) % KEYSTROKE_CHUNK_SIZE == 0 {
+            std::thread::sleep(std::time::Duration::from_millis(KEYSTROKE_CHUNK_PAUSE_MS));
+        } else {
+            std::thread::sleep(std::time::Duration::from_millis(KEYSTROKE_DELAY_MS));
+        }
+    }
+    Ok(())
+}
+
 fn copy_to_clipboard(text: &str) -> Result<()> {
     use std::process::Command;
     use std::io::Write;
@@ -55,6 +143,177 @@ fn copy_to_clipboard(text: &str) -> Result<()> {
     Ok(())
 }
 
+fn read_clipboard() -> Result<String> {
+    let output = std::process::Command::new("pbpaste")
+        .env("LANG", "en_US.UTF-8")
+        .output()?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn cf_string(s: &str) -> CFStringRef {
+    unsafe {
+        CFStringCreateWithBytes(
+            std::ptr::null(),
+            s.as_ptr(),
+            s.len() as isize,
+            K_CF_STRING_ENCODING_UTF8,
+            false,
+        )
+    }
+}
+
+/// Converts a CFString to a Rust String. Does not release `string`.
+fn cf_string_to_string(string: CFStringRef) -> Option<String> {
+    unsafe {
+        if string.is_null() || CFGetTypeID(string) != CFStringGetTypeID() {
+            return None;
+        }
+        let length = CFStringGetLength(string);
+        let capacity = CFStringGetMaximumSizeForEncoding(length, K_CF_STRING_ENCODING_UTF8) + 1;
+        let mut buffer = vec![0u8; capacity as usize];
+        if !CFStringGetCString(string, buffer.as_mut_ptr() as *mut _, capacity, K_CF_STRING_ENCODING_UTF8) {
+            return None;
+        }
+        let end = buffer.iter().position(|&b| b == 0).unwrap_or(buffer.len());
+        buffer.truncate(end);
+        String::from_utf8(buffer).ok()
+    }
+}
+
+/// Copies an AX attribute of `element`. The returned value is owned by the caller.
+fn ax_copy_attribute(element: AXUIElementRef, attribute: &str) -> Option<*const std::ffi::c_void> {
+    unsafe {
+        let name = cf_string(attribute);
+        if name.is_null() {
+            return None;
+        }
+        let mut value: *const std::ffi::c_void = std::ptr::null();
+        let err = AXUIElementCopyAttributeValue(element, name, &mut value);
+        CFRelease(name);
+        if err != K_AX_ERROR_SUCCESS || value.is_null() {
+            return None;
+        }
+        Some(value)
+    }
+}
+
+/// Reads AXSelectedText from the focused UI element. Works for native Cocoa text views;
+/// many Electron/web apps don't expose it.
+fn selected_text_via_accessibility() -> Option<String> {
+    unsafe {
+        let system = AXUIElementCreateSystemWide();
+        if system.is_null() {
+            return None;
+        }
+        let focused = ax_copy_attribute(system, "AXFocusedUIElement");
+        CFRelease(system);
+        let focused = focused?;
+
+        let selected = ax_copy_attribute(focused, "AXSelectedText");
+        CFRelease(focused);
+        let selected = selected?;
+
+        let text = cf_string_to_string(selected);
+        CFRelease(selected);
+        text
+    }
+}
+
+/// Fallback: simulate Cmd+C and read the clipboard, then put the previous contents
+/// back, even if the copy failed.
+fn selected_text_via_copy() -> Result<Option<String>> {
+    let snapshot = snapshot_clipboard();
+
+    let copied = (|| -> Result<String> {
+        // Clear first so an unchanged clipboard isn't mistaken for the selection
+        copy_to_clipboard("")?;
+        simulate_cmd_c()?;
+        std::thread::sleep(std::time::Duration::from_millis(COPY_SETTLE_MS));
+        read_clipboard()
+    })();
+
+    restore_clipboard(snapshot);
+
+    let copied = copied?;
+    Ok(if copied.is_empty() { None } else { Some(copied) })
+}
+
+/// Every pasteboard item with all of its representations, so images, files and
+/// rich text survive a paste, not just plain text.
+struct ClipboardSnapshot {
+    items: Vec<Vec<(String, Vec<u8>)>>,
+}
+
+fn snapshot_clipboard() -> ClipboardSnapshot {
+    use objc2_app_kit::NSPasteboard;
+
+    let pasteboard = NSPasteboard::generalPasteboard();
+    let items = pasteboard
+        .pasteboardItems()
+        .map(|items| items.to_vec())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|item| {
+            item.types()
+                .to_vec()
+                .into_iter()
+                .filter_map(|ty| {
+                    let data = item.dataForType(&ty)?;
+                    Some((ty.to_string(), data.to_vec()))
+                })
+                .collect::<Vec<_>>()
+        })
+        .filter(|reps| !reps.is_empty())
+        .collect();
+    ClipboardSnapshot { items }
+}
+
+fn clipboard_change_count() -> isize {
+    objc2_app_kit::NSPasteboard::generalPasteboard().changeCount()
+}
+
+fn restore_clipboard(snapshot: ClipboardSnapshot) {
+    use objc2::runtime::ProtocolObject;
+    use objc2_app_kit::{NSPasteboard, NSPasteboardItem, NSPasteboardWriting};
+    use objc2_foundation::{NSArray, NSData, NSString};
+
+    let pasteboard = NSPasteboard::generalPasteboard();
+    pasteboard.clearContents();
+    if snapshot.items.is_empty() {
+        return;
+    }
+
+    let items: Vec<objc2::rc::Retained<ProtocolObject<dyn NSPasteboardWriting>>> = snapshot
+        .items
+        .iter()
+        .map(|reps| {
+            let item = NSPasteboardItem::new();
+            for (ty, data) in reps {
+                item.setData_forType(&NSData::with_bytes(data), &NSString::from_str(ty));
+            }
+            ProtocolObject::from_retained(item)
+        })
+        .collect();
+    if !pasteboard.writeObjects(&NSArray::from_retained_slice(&items)) {
+        tracing::warn!("Failed to restore clipboard contents");
+    }
+}
+
+/// Puts the snapshot back after `delay`, unless something else wrote to the
+/// clipboard since our paste (the user copied again).
+fn schedule_clipboard_restore(snapshot: ClipboardSnapshot, delay: std::time::Duration) {
+    let expected = clipboard_change_count();
+    std::thread::spawn(move || {
+        std::thread::sleep(delay);
+        if clipboard_change_count() != expected {
+            tracing::info!("Clipboard changed since paste — not restoring previous contents");
+            return;
+        }
+        restore_clipboard(snapshot);
+        tracing::info!("Previous clipboard contents restored");
+    });
+}
+
 pub struct MacOsTextInjector;
 
 impl MacOsTextInjector {
@@ -65,10 +324,15 @@ impl MacOsTextInjector {
 
 impl TextInjector for MacOsTextInjector {
     fn inject_text(&self, text: &str) -> Result<()> {
-        self.inject_via_clipboard(text)
+        if !self.is_accessibility_granted() {
+            anyhow::bail!("Accessibility permission is required to simulate keystrokes");
+        }
+        tracing::info!("Typing {} chars via keystrokes", text.chars().count());
+        type_text(text)
     }
 
-    fn inject_via_clipboard(&self, text: &str) -> Result<()> {
+    fn inject_via_clipboard(&self, text: &str, restore_after: Option<std::time::Duration>) -> Result<()> {
+        let snapshot = restore_after.map(|_| snapshot_clipboard());
         copy_to_clipboard(text)?;
         let trusted = self.is_accessibility_granted();
         tracing::info!("AXIsProcessTrusted() = {}, attempting text injection ({} chars)", trusted, text.len());
@@ -78,6 +342,9 @@ impl TextInjector for MacOsTextInjector {
                 tracing::warn!("CGEvent Cmd+V failed: {}. Text is in clipboard.", e);
             } else {
                 tracing::info!("Cmd+V simulated successfully");
+                if let (Some(snapshot), Some(delay)) = (snapshot, restore_after) {
+                    schedule_clipboard_restore(snapshot, delay);
+                }
             }
         } else {
             tracing::warn!("Accessibility not granted — text copied to clipboard but cannot auto-paste. Grant permission in System Settings > Privacy & Security > Accessibility.");
@@ -85,6 +352,10 @@ impl TextInjector for MacOsTextInjector {
         Ok(())
     }
 
+    fn copy_to_clipboard(&self, text: &str) -> Result<()> {
+        copy_to_clipboard(text)
+    }
+
     fn is_accessibility_granted(&self) -> bool {
         unsafe { AXIsProcessTrusted() }
     }
@@ -168,7 +439,18 @@ impl MacOsTextSelector {
 
 impl TextSelector for MacOsTextSelector {
     fn get_selected_text(&self) -> Result<Option<String>> {
-        todo!("macOS text selection via Accessibility API - Phase 6 (TTS)")
+        if !unsafe { AXIsProcessTrusted() } {
+            anyhow::bail!("Accessibility permission is required to read the selected text");
+        }
+
+        if let Some(text) = selected_text_via_accessibility().filter(|t| !t.is_empty()) {
+            tracing::info!("Selected text read via Accessibility ({} chars)", text.len());
+            return Ok(Some(text));
+        }
+
+        let text = selected_text_via_copy()?;
+        tracing::info!("Selected text read via Cmd+C fallback ({} chars)", text.as_ref().map_or(0, |t| t.len()));
+        Ok(text)
     }
 
     fn is_supported(&self) -> bool {
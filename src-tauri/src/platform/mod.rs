@@ -2,12 +2,22 @@
 mod macos;
 #[cfg(target_os = "macos")]
 pub use macos::*;
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "linux")]
+pub use linux::*;
 
 use anyhow::Result;
+use std::time::Duration;
 
 pub trait TextInjector: Send + Sync {
+    /// Types `text` as synthesized keystrokes, for apps that block paste.
     fn inject_text(&self, text: &str) -> Result<()>;
-    fn inject_via_clipboard(&self, text: &str) -> Result<()>;
+    /// Pastes `text` via the clipboard. With `restore_after`, the previous clipboard
+    /// contents are put back once that delay has passed.
+    fn inject_via_clipboard(&self, text: &str, restore_after: Option<Duration>) -> Result<()>;
+    /// Puts `text` on the clipboard without pasting it.
+    fn copy_to_clipboard(&self, text: &str) -> Result<()>;
     fn is_accessibility_granted(&self) -> bool;
     fn request_accessibility(&self) -> Result<()>;
 }
@@ -27,18 +37,41 @@ pub fn get_text_injector() -> Box<dyn TextInjector> {
     {
         Box::new(MacOsTextInjector::new())
     }
-    #[cfg(not(target_os = "macos"))]
+    #[cfg(target_os = "linux")]
+    {
+        Box::new(LinuxTextInjector::new())
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
     {
         panic!("Text injection not supported on this platform")
     }
 }
 
+pub fn get_text_selector() -> Box<dyn TextSelector> {
+    #[cfg(target_os = "macos")]
+    {
+        Box::new(MacOsTextSelector::new())
+    }
+    #[cfg(target_os = "linux")]
+    {
+        Box::new(LinuxTextSelector::new())
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    {
+        panic!("Text selection not supported on this platform")
+    }
+}
+
 pub fn get_media_controller() -> &'static dyn MediaController {
     #[cfg(target_os = "macos")]
     {
         MacOsMediaController::instance()
     }
-    #[cfg(not(target_os = "macos"))]
+    #[cfg(target_os = "linux")]
+    {
+        LinuxMediaController::instance()
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
     {
         panic!("Media control not supported on this platform")
     }
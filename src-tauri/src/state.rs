@@ -4,7 +4,7 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use serde::{Serialize, Deserialize};
 
-use crate::engine::{SttEngine, TtsEngine, Segment};
+use crate::engine::{SttEngine, TtsEngine, Segment, Word, DecodingConfig};
 
 pub type CancelFlag = Arc<AtomicBool>;
 
@@ -14,6 +14,8 @@ pub enum AppStatus {
     Idle,
     Loading,
     Recording,
+    /// Voice-activated mode is listening for the next utterance.
+    Armed,
     Transcribing,
     Synthesizing,
     Playing,
@@ -32,6 +34,18 @@ pub struct StreamingState {
     pub locked_language: Option<String>,
     pub total_duration_ms: u64,
     pub segments: Vec<Segment>,
+    pub words: Vec<Word>,
+    /// Byte length of `completed_text` already typed into the focused app (live typing).
+    pub injected_len: usize,
+    /// This session translates to English (Whisper only).
+    pub translate: bool,
+}
+
+/// Listener thread of `RecordingMode::VoiceActivated`. Setting `stop` disarms it; the
+/// thread transcribes any utterance in progress before exiting.
+pub struct VoiceSession {
+    pub stop: CancelFlag,
+    pub thread: std::thread::JoinHandle<()>,
 }
 
 pub struct AppState {
@@ -43,8 +57,16 @@ pub struct AppState {
     pub download_cancels: Mutex<HashMap<String, CancelFlag>>,
     pub streaming_state: Mutex<Option<StreamingState>>,
     pub streaming_thread: Mutex<Option<std::thread::JoinHandle<()>>>,
+    pub file_transcription_cancel: Mutex<Option<CancelFlag>>,
+    pub voice_session: Mutex<Option<VoiceSession>>,
+    pub warm_mic: Mutex<Option<Arc<crate::audio::capture::WarmMic>>>,
     pub tray_stt_shortcut_item: Mutex<Option<tauri::menu::MenuItem<tauri::Wry>>>,
+    pub tray_tts_shortcut_item: Mutex<Option<tauri::menu::MenuItem<tauri::Wry>>>,
+    pub tray_warm_mic_item: Mutex<Option<tauri::menu::MenuItem<tauri::Wry>>>,
     pub idle_timer_abort: Mutex<Option<tauri::async_runtime::JoinHandle<()>>>,
+    pub audio_playback: Mutex<Option<crate::audio::AudioPlayback>>,
+    pub tts_session: Mutex<u64>,
+    pub tts_idle_timer_abort: Mutex<Option<tauri::async_runtime::JoinHandle<()>>>,
 }
 
 impl AppState {
@@ -58,8 +80,16 @@ impl AppState {
             download_cancels: Mutex::new(HashMap::new()),
             streaming_state: Mutex::new(None),
             streaming_thread: Mutex::new(None),
+            file_transcription_cancel: Mutex::new(None),
+            voice_session: Mutex::new(None),
+            warm_mic: Mutex::new(None),
             tray_stt_shortcut_item: Mutex::new(None),
+            tray_tts_shortcut_item: Mutex::new(None),
+            tray_warm_mic_item: Mutex::new(None),
             idle_timer_abort: Mutex::new(None),
+            audio_playback: Mutex::new(None),
+            tts_session: Mutex::new(0),
+            tts_idle_timer_abort: Mutex::new(None),
         }
     }
 }
@@ -70,6 +100,8 @@ pub struct Settings {
     pub stt: SttSettings,
     pub tts: TtsSettings,
     pub general: GeneralSettings,
+    #[serde(default)]
+    pub history: HistorySettings,
 }
 
 impl Default for Settings {
@@ -79,6 +111,7 @@ impl Default for Settings {
             stt: SttSettings::default(),
             tts: TtsSettings::default(),
             general: GeneralSettings::default(),
+            history: HistorySettings::default(),
         }
     }
 }
@@ -87,6 +120,9 @@ impl Default for Settings {
 pub struct ShortcutSettings {
     pub stt: String,
     pub tts: String,
+    /// Starts a dictation session that is translated to English. Unset by default.
+    #[serde(default)]
+    pub translate: Option<String>,
 }
 
 impl Default for ShortcutSettings {
@@ -94,6 +130,7 @@ impl Default for ShortcutSettings {
         Self {
             stt: "Alt+Space".to_string(),
             tts: "Alt+Shift+Space".to_string(),
+            translate: None,
         }
     }
 }
@@ -102,6 +139,14 @@ fn default_idle_timeout() -> Option<u64> {
     Some(300)
 }
 
+fn default_restore_clipboard() -> bool {
+    true
+}
+
+fn default_clipboard_restore_delay_ms() -> u64 {
+    500
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SttSettings {
     pub language: String,
@@ -111,6 +156,198 @@ pub struct SttSettings {
     pub active_model_id: Option<String>,
     #[serde(default = "default_idle_timeout")]
     pub model_idle_timeout_s: Option<u64>,
+    /// Put the user's previous clipboard back after pasting. When false, the
+    /// transcript is left on the clipboard.
+    #[serde(default = "default_restore_clipboard")]
+    pub restore_clipboard: bool,
+    #[serde(default = "default_clipboard_restore_delay_ms")]
+    pub clipboard_restore_delay_ms: u64,
+    /// Inject each finalized streaming chunk as soon as it is transcribed.
+    #[serde(default)]
+    pub live_typing: bool,
+    /// Hands-free stop for `RecordingMode::Toggle`.
+    #[serde(default)]
+    pub auto_stop: AutoStopSettings,
+    /// Input device names in order of preference. Unavailable devices are skipped, and
+    /// the system default is used when none is connected.
+    #[serde(default)]
+    pub preferred_input_devices: Vec<String>,
+    /// Keep the microphone open between recordings to capture a pre-roll.
+    #[serde(default)]
+    pub warm_mic: WarmMicSettings,
+    /// Utterance detection for `RecordingMode::VoiceActivated`.
+    #[serde(default)]
+    pub voice_activation: VoiceActivationSettings,
+    /// Drop non-speech audio before transcription and cut chunks between utterances.
+    #[serde(default = "default_vad_enabled")]
+    pub vad_enabled: bool,
+    /// Languages the user speaks. With "auto", detection only picks among these;
+    /// empty allows any language.
+    #[serde(default)]
+    pub allowed_languages: Vec<String>,
+    /// Sessions started with the dictation shortcut are translated to English.
+    #[serde(default)]
+    pub translate_to_english: bool,
+    /// Decoder settings keyed by model id. Models without an entry use the defaults.
+    #[serde(default)]
+    pub decoding: HashMap<String, DecodingConfig>,
+    /// Names, jargon and acronyms the engines should favour.
+    #[serde(default)]
+    pub hotwords: Vec<Hotword>,
+    #[serde(default = "default_hotword_boost")]
+    pub hotword_boost: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutoStopSettings {
+    pub enabled: bool,
+    /// Stop after this much continuous silence following speech.
+    #[serde(default = "default_auto_stop_silence_s")]
+    pub silence_s: f32,
+    /// Cancel the recording if no speech is heard this long after it starts.
+    #[serde(default = "default_no_speech_timeout_s")]
+    pub no_speech_timeout_s: Option<f32>,
+}
+
+fn default_auto_stop_silence_s() -> f32 {
+    2.0
+}
+
+fn default_no_speech_timeout_s() -> Option<f32> {
+    Some(10.0)
+}
+
+impl Default for AutoStopSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            silence_s: default_auto_stop_silence_s(),
+            no_speech_timeout_s: default_no_speech_timeout_s(),
+        }
+    }
+}
+
+/// The pre-roll only ever lives in memory: audio older than `pre_roll_ms` is
+/// discarded as new audio arrives.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WarmMicSettings {
+    pub enabled: bool,
+    /// Clamped to 300–1000 ms.
+    #[serde(default = "default_warm_mic_pre_roll_ms")]
+    pub pre_roll_ms: u64,
+}
+
+fn default_warm_mic_pre_roll_ms() -> u64 {
+    500
+}
+
+impl Default for WarmMicSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            pre_roll_ms: default_warm_mic_pre_roll_ms(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VoiceSensitivity {
+    Low,
+    Medium,
+    High,
+}
+
+impl Default for VoiceSensitivity {
+    fn default() -> Self {
+        Self::Medium
+    }
+}
+
+impl VoiceSensitivity {
+    /// How far above the noise floor the input must rise to count as speech.
+    pub fn threshold_db(self) -> f32 {
+        match self {
+            Self::Low => 12.0,
+            Self::Medium => 9.0,
+            Self::High => 6.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoiceActivationSettings {
+    #[serde(default)]
+    pub sensitivity: VoiceSensitivity,
+    /// Silence that ends an utterance.
+    #[serde(default = "default_end_silence_ms")]
+    pub end_silence_ms: u64,
+    /// Audio kept from before speech is detected, so the first syllable isn't clipped.
+    #[serde(default = "default_voice_pre_roll_ms")]
+    pub pre_roll_ms: u64,
+    /// An utterance is cut and transcribed after this long, even without a pause.
+    #[serde(default = "default_max_utterance_s")]
+    pub max_utterance_s: f32,
+}
+
+fn default_end_silence_ms() -> u64 {
+    800
+}
+
+fn default_voice_pre_roll_ms() -> u64 {
+    500
+}
+
+fn default_max_utterance_s() -> f32 {
+    30.0
+}
+
+impl Default for VoiceActivationSettings {
+    fn default() -> Self {
+        Self {
+            sensitivity: VoiceSensitivity::default(),
+            end_silence_ms: default_end_silence_ms(),
+            pre_roll_ms: default_voice_pre_roll_ms(),
+            max_utterance_s: default_max_utterance_s(),
+        }
+    }
+}
+
+fn default_vad_enabled() -> bool {
+    true
+}
+
+fn default_hotword_boost() -> f32 {
+    2.0
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Hotword {
+    pub phrase: String,
+    /// Only used when transcribing this language. `None` applies to every language.
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+impl SttSettings {
+    pub fn decoding_for(&self, model_id: Option<&str>) -> DecodingConfig {
+        model_id
+            .and_then(|id| self.decoding.get(id))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Phrases that apply to `language`. With auto-detection every phrase applies.
+    pub fn hotwords_for(&self, language: Option<&str>) -> Vec<String> {
+        self.hotwords
+            .iter()
+            .filter(|h| match (h.language.as_deref(), language) {
+                (Some(scope), Some(lang)) => scope == lang,
+                _ => true,
+            })
+            .map(|h| h.phrase.clone())
+            .collect()
+    }
 }
 
 impl Default for SttSettings {
@@ -121,6 +358,19 @@ impl Default for SttSettings {
             recording_mode: RecordingMode::default(),
             active_model_id: None,
             model_idle_timeout_s: Some(300),
+            restore_clipboard: true,
+            clipboard_restore_delay_ms: 500,
+            live_typing: false,
+            auto_stop: AutoStopSettings::default(),
+            preferred_input_devices: Vec::new(),
+            warm_mic: WarmMicSettings::default(),
+            voice_activation: VoiceActivationSettings::default(),
+            vad_enabled: true,
+            allowed_languages: Vec::new(),
+            translate_to_english: false,
+            decoding: HashMap::new(),
+            hotwords: Vec::new(),
+            hotword_boost: default_hotword_boost(),
         }
     }
 }
@@ -137,6 +387,9 @@ pub enum InjectionMode {
 pub enum RecordingMode {
     Toggle,
     PushToTalk,
+    /// The shortcut arms and disarms a listener that transcribes each utterance as
+    /// soon as it ends.
+    VoiceActivated,
 }
 
 impl Default for RecordingMode {
@@ -150,6 +403,8 @@ pub struct TtsSettings {
     pub active_model_id: Option<String>,
     pub speed: f32,
     pub voice_id: Option<String>,
+    #[serde(default = "default_idle_timeout")]
+    pub model_idle_timeout_s: Option<u64>,
 }
 
 impl Default for TtsSettings {
@@ -158,6 +413,7 @@ impl Default for TtsSettings {
             active_model_id: None,
             speed: 1.0,
             voice_id: None,
+            model_idle_timeout_s: Some(300),
         }
     }
 }
@@ -179,3 +435,21 @@ impl Default for GeneralSettings {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistorySettings {
+    pub enabled: bool,
+    /// Entries older than this are pruned. `None` keeps them forever.
+    pub retention_days: Option<u32>,
+    pub max_entries: Option<usize>,
+}
+
+impl Default for HistorySettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            retention_days: Some(30),
+            max_entries: Some(1000),
+        }
+    }
+}
@@ -23,8 +23,19 @@ export const getActiveModel = (capability) =>
     invoke('get_active_model', { capability });
 
 export const startRecording = () => invoke('start_recording');
+export const listInputDevices = () => invoke('list_input_devices');
+export const setPreferredInputDevices = (devices) => invoke('set_preferred_input_devices', { devices });
+export const getWarmMicStatus = () => invoke('get_warm_mic_status');
+export const armVoiceActivation = () => invoke('arm_voice_activation');
+export const disarmVoiceActivation = () => invoke('disarm_voice_activation');
 export const stopRecording = () => invoke('stop_recording');
 export const getStatus = () => invoke('get_status');
+export const transcribeFile = (path) => invoke('transcribe_file', { path });
+export const cancelFileTranscription = () => invoke('cancel_file_transcription');
+export const exportTranscription = (result, format, path, options) =>
+    invoke('export_transcription', { result, format, path, options });
+export const exportHistoryEntry = (id, format, path, options) =>
+    invoke('export_history_entry', { id, format, path, options });
 
 export const speakSelectedText = () => invoke('speak_selected_text');
 export const speakText = (text) => invoke('speak_text', { text });
@@ -32,12 +43,27 @@ export const stopSpeaking = () => invoke('stop_speaking');
 
 export const getSettings = () => invoke('get_settings');
 export const updateSettings = (settings) => invoke('update_settings', { settings });
+export const getDecodingSettings = (modelId) => invoke('get_decoding_settings', { modelId });
+export const updateDecodingSettings = (modelId, config) => invoke('update_decoding_settings', { modelId, config });
+export const getHotwords = () => invoke('get_hotwords');
+export const addHotword = (phrase, language) => invoke('add_hotword', { phrase, language });
+export const removeHotword = (phrase, language) => invoke('remove_hotword', { phrase, language });
 export const updateSttShortcut = (shortcut) => invoke('update_stt_shortcut', { shortcut });
+export const updateTranslateShortcut = (shortcut) => invoke('update_translate_shortcut', { shortcut });
 export const checkAccessibilityPermission = () => invoke('check_accessibility_permission');
 export const requestAccessibilityPermission = () => invoke('request_accessibility_permission');
 export const getSttShortcutLabel = () => invoke('get_stt_shortcut_label');
+export const updateTtsShortcut = (shortcut) => invoke('update_tts_shortcut', { shortcut });
+export const getTtsShortcutLabel = () => invoke('get_tts_shortcut_label');
 export const getAppVersion = () => invoke('get_app_version');
 
+export const listHistory = (limit) => invoke('list_history', { limit });
+export const searchHistory = (query) => invoke('search_history', { query });
+export const deleteHistoryEntry = (id) => invoke('delete_history_entry', { id });
+export const clearHistory = () => invoke('clear_history');
+export const copyHistoryEntry = (id) => invoke('copy_history_entry', { id });
+export const reinjectHistoryEntry = (id) => invoke('reinject_history_entry', { id });
+
 export const completeOnboarding = () => invoke('complete_onboarding');
 export const finishOnboarding = () => invoke('finish_onboarding');
 export const rerunOnboarding = () => invoke('rerun_onboarding');
@@ -53,9 +79,14 @@ export const onAudioLevel = (callback) => listen('audio-level', (e) => callback(
 export const onTranscriptionComplete = (callback) => listen('transcription-complete', (e) => callback(e.payload));
 export const onTranscriptionProgress = (callback) => listen('transcription-progress', (e) => callback(e.payload));
 export const onStreamingTranscription = (callback) => listen('streaming-transcription', (e) => callback(e.payload));
+export const onAutoStopCountdown = (callback) => listen('auto-stop-countdown', (e) => callback(e.payload));
 export const onOverlayMode = (callback) => listen('overlay-mode', (e) => callback(e.payload));
 export const onPlaybackStatus = (callback) => listen('playback-status', (e) => callback(e.payload));
 export const onPlaybackProgress = (callback) => listen('playback-progress', (e) => callback(e.payload));
 export const onSttShortcutChanged = (callback) => listen('stt-shortcut-changed', (e) => callback(e.payload));
+export const onTtsShortcutChanged = (callback) => listen('tts-shortcut-changed', (e) => callback(e.payload));
 export const onNavigateTab = (callback) => listen('navigate-tab', (e) => callback(e.payload));
 export const onPermissionMissing = (callback) => listen('permission-missing', (e) => callback(e.payload));
+export const onHistoryUpdated = (callback) => listen('history-updated', (e) => callback(e.payload));
+export const onDeviceChanged = (callback) => listen('device-changed', (e) => callback(e.payload));
+export const onWarmMicStatus = (callback) => listen('warm-mic-status', (e) => callback(e.payload));
@@ -21,13 +21,39 @@ async function loadSettings() {
         const sttShortcutEl = document.getElementById('stt-shortcut');
         sttShortcutEl.value = settings.shortcuts.stt || 'Alt+Space';
         sttShortcutEl.dataset.previousValue = sttShortcutEl.value;
+        const ttsShortcutEl = document.getElementById('tts-shortcut');
+        ttsShortcutEl.value = settings.shortcuts.tts || 'Alt+Shift+Space';
+        ttsShortcutEl.dataset.previousValue = ttsShortcutEl.value;
+        const translateShortcutEl = document.getElementById('translate-shortcut');
+        translateShortcutEl.value = settings.shortcuts.translate || '';
+        translateShortcutEl.dataset.previousValue = translateShortcutEl.value;
         document.getElementById('language-select').value = settings.stt.language;
         document.getElementById('injection-mode').value = settings.stt.injection_mode;
         document.getElementById('recording-mode').value = settings.stt.recording_mode || 'toggle';
+        document.getElementById('voice-sensitivity').value = settings.stt.voice_activation.sensitivity;
+        document.getElementById('voice-end-silence').value = String(settings.stt.voice_activation.end_silence_ms);
+        document.getElementById('live-typing').checked = settings.stt.live_typing;
+        document.getElementById('vad-enabled').checked = settings.stt.vad_enabled;
+        document.getElementById('warm-mic-enabled').checked = settings.stt.warm_mic.enabled;
+        document.getElementById('warm-mic-pre-roll').value = String(settings.stt.warm_mic.pre_roll_ms);
+        document.getElementById('auto-stop-enabled').checked = settings.stt.auto_stop.enabled;
+        document.getElementById('auto-stop-silence').value = String(settings.stt.auto_stop.silence_s);
+        const noSpeechVal = settings.stt.auto_stop.no_speech_timeout_s;
+        document.getElementById('auto-stop-no-speech').value = noSpeechVal === null ? 'never' : String(noSpeechVal);
+        document.getElementById('translate-to-english').checked = settings.stt.translate_to_english;
+        const allowed = settings.stt.allowed_languages || [];
+        document.querySelectorAll('#allowed-languages input').forEach(el => {
+            el.checked = allowed.includes(el.value);
+        });
+        document.getElementById('restore-clipboard').checked = settings.stt.restore_clipboard;
+        document.getElementById('clipboard-restore-delay').value = String(settings.stt.clipboard_restore_delay_ms);
         const timeoutVal = settings.stt.model_idle_timeout_s;
         document.getElementById('idle-timeout').value = timeoutVal === null ? 'never' : String(timeoutVal);
         document.getElementById('launch-at-login').checked = settings.general.launch_at_login;
         document.getElementById('sound-feedback').checked = settings.general.sound_feedback;
+        document.getElementById('history-enabled').checked = settings.history.enabled;
+        const retentionVal = settings.history.retention_days;
+        document.getElementById('history-retention').value = retentionVal === null ? 'forever' : String(retentionVal);
     } catch (e) {
         console.error('Failed to load settings:', e);
     }
@@ -35,31 +61,61 @@ async function loadSettings() {
 
 async function saveSettings() {
     try {
+        const current = await api.getSettings();
         const settings = {
+            ...current,
             shortcuts: {
+                ...current.shortcuts,
                 stt: document.getElementById('stt-shortcut').value,
-                tts: 'Alt+Shift+Space',
             },
             stt: {
+                ...current.stt,
                 language: document.getElementById('language-select').value,
                 injection_mode: document.getElementById('injection-mode').value,
                 recording_mode: document.getElementById('recording-mode').value,
-                active_model_id: null,
+                live_typing: document.getElementById('live-typing').checked,
+                vad_enabled: document.getElementById('vad-enabled').checked,
+                warm_mic: {
+                    enabled: document.getElementById('warm-mic-enabled').checked,
+                    pre_roll_ms: parseInt(document.getElementById('warm-mic-pre-roll').value, 10),
+                },
+                auto_stop: {
+                    enabled: document.getElementById('auto-stop-enabled').checked,
+                    silence_s: parseFloat(document.getElementById('auto-stop-silence').value),
+                    no_speech_timeout_s: (() => {
+                        const v = document.getElementById('auto-stop-no-speech').value;
+                        return v === 'never' ? null : parseFloat(v);
+                    })(),
+                },
+                voice_activation: {
+                    ...current.stt.voice_activation,
+                    sensitivity: document.getElementById('voice-sensitivity').value,
+                    end_silence_ms: parseInt(document.getElementById('voice-end-silence').value, 10),
+                },
+                translate_to_english: document.getElementById('translate-to-english').checked,
+                allowed_languages: Array.from(document.querySelectorAll('#allowed-languages input:checked'))
+                    .map(el => el.value),
+                restore_clipboard: document.getElementById('restore-clipboard').checked,
+                clipboard_restore_delay_ms: parseInt(document.getElementById('clipboard-restore-delay').value, 10),
                 model_idle_timeout_s: (() => {
                     const v = document.getElementById('idle-timeout').value;
                     return v === 'never' ? null : parseInt(v, 10);
                 })(),
             },
-            tts: {
-                active_model_id: null,
-                speed: 1.0,
-                voice_id: null,
-            },
             general: {
+                ...current.general,
                 launch_at_login: document.getElementById('launch-at-login').checked,
                 sound_feedback: document.getElementById('sound-feedback').checked,
                 onboarding_completed: true,
             },
+            history: {
+                ...current.history,
+                enabled: document.getElementById('history-enabled').checked,
+                retention_days: (() => {
+                    const v = document.getElementById('history-retention').value;
+                    return v === 'forever' ? null : parseInt(v, 10);
+                })(),
+            },
         };
         await api.updateSettings(settings);
     } catch (e) {
@@ -67,13 +123,17 @@ async function saveSettings() {
     }
 }
 
-['language-select', 'injection-mode', 'recording-mode', 'idle-timeout'].forEach(id => {
+['language-select', 'injection-mode', 'recording-mode', 'idle-timeout', 'clipboard-restore-delay', 'history-retention', 'auto-stop-silence', 'auto-stop-no-speech', 'voice-sensitivity', 'voice-end-silence', 'warm-mic-pre-roll'].forEach(id => {
     document.getElementById(id).addEventListener('change', saveSettings);
 });
-['launch-at-login', 'sound-feedback'].forEach(id => {
+['launch-at-login', 'sound-feedback', 'restore-clipboard', 'live-typing', 'vad-enabled', 'warm-mic-enabled', 'auto-stop-enabled', 'translate-to-english', 'history-enabled'].forEach(id => {
     document.getElementById(id).addEventListener('change', saveSettings);
 });
 
+document.querySelectorAll('#allowed-languages input').forEach(el => {
+    el.addEventListener('change', saveSettings);
+});
+
 document.getElementById('stt-shortcut').addEventListener('change', async (e) => {
     const select = e.target;
     const newShortcut = select.value;
@@ -87,6 +147,32 @@ document.getElementById('stt-shortcut').addEventListener('change', async (e) =>
     }
 });
 
+document.getElementById('translate-shortcut').addEventListener('change', async (e) => {
+    const select = e.target;
+    const newShortcut = select.value || null;
+    const previousValue = select.dataset.previousValue || '';
+    try {
+        await api.updateTranslateShortcut(newShortcut);
+        select.dataset.previousValue = select.value;
+    } catch (err) {
+        console.error('Failed to update translate shortcut:', err);
+        select.value = previousValue;
+    }
+});
+
+document.getElementById('tts-shortcut').addEventListener('change', async (e) => {
+    const select = e.target;
+    const newShortcut = select.value;
+    const previousValue = select.dataset.previousValue || 'Alt+Shift+Space';
+    try {
+        await api.updateTtsShortcut(newShortcut);
+        select.dataset.previousValue = newShortcut;
+    } catch (err) {
+        console.error('Failed to update TTS shortcut:', err);
+        select.value = previousValue;
+    }
+});
+
 async function checkAccessibility() {
     try {
         const granted = await api.checkAccessibilityPermission();
@@ -328,6 +414,190 @@ api.onDownloadError((data) => {
     loadCatalog();
 });
 
+function renderHotwords(hotwords) {
+    const container = document.getElementById('hotword-list');
+    container.innerHTML = '';
+
+    for (const hotword of hotwords) {
+        const card = document.createElement('div');
+        card.className = 'model-card';
+
+        const infoDiv = document.createElement('div');
+        infoDiv.className = 'model-info';
+        const nameDiv = document.createElement('div');
+        nameDiv.className = 'model-name';
+        nameDiv.textContent = hotword.phrase;
+        infoDiv.appendChild(nameDiv);
+        if (hotword.language) {
+            const metaDiv = document.createElement('div');
+            metaDiv.className = 'model-meta';
+            const span = document.createElement('span');
+            span.className = 'model-lang';
+            span.textContent = hotword.language;
+            metaDiv.appendChild(span);
+            infoDiv.appendChild(metaDiv);
+        }
+
+        const deleteBtn = document.createElement('button');
+        deleteBtn.className = 'btn-delete';
+        deleteBtn.title = 'Remove';
+        deleteBtn.textContent = '\u2715';
+        deleteBtn.addEventListener('click', async () => {
+            try {
+                renderHotwords(await api.removeHotword(hotword.phrase, hotword.language));
+            } catch (err) {
+                console.error('Failed to remove hotword:', err);
+            }
+        });
+
+        card.appendChild(infoDiv);
+        card.appendChild(deleteBtn);
+        container.appendChild(card);
+    }
+}
+
+async function loadHotwords() {
+    try {
+        renderHotwords(await api.getHotwords());
+    } catch (e) {
+        console.error('Failed to load hotwords:', e);
+    }
+}
+
+async function addHotword() {
+    const input = document.getElementById('hotword-input');
+    const phrase = input.value.trim();
+    if (!phrase) return;
+    const language = document.getElementById('hotword-language').value || null;
+    try {
+        renderHotwords(await api.addHotword(phrase, language));
+        input.value = '';
+    } catch (err) {
+        console.error('Failed to add hotword:', err);
+    }
+}
+
+document.getElementById('add-hotword').addEventListener('click', addHotword);
+document.getElementById('hotword-input').addEventListener('keydown', (e) => {
+    if (e.key === 'Enter') addHotword();
+});
+
+const HISTORY_PAGE_SIZE = 200;
+
+async function loadHistory() {
+    try {
+        const query = document.getElementById('history-search').value.trim();
+        const entries = query
+            ? await api.searchHistory(query)
+            : await api.listHistory(HISTORY_PAGE_SIZE);
+        const container = document.getElementById('history-list');
+
+        if (entries.length === 0) {
+            container.innerHTML = query
+                ? '<p class="empty-state">No transcriptions match your search.</p>'
+                : '<p class="empty-state">No transcriptions yet.</p>';
+            return;
+        }
+
+        container.innerHTML = '';
+
+        for (const entry of entries) {
+            const card = document.createElement('div');
+            card.className = 'model-card';
+
+            const infoDiv = document.createElement('div');
+            infoDiv.className = 'model-info';
+            const textDiv = document.createElement('div');
+            textDiv.className = 'history-text';
+            textDiv.textContent = entry.text;
+            textDiv.title = entry.text;
+            const metaDiv = document.createElement('div');
+            metaDiv.className = 'model-meta';
+            const meta = [
+                new Date(entry.timestamp_ms).toLocaleString(),
+                formatTime(Math.round(entry.duration_ms / 1000)),
+                entry.language,
+                entry.model_id,
+            ].filter(Boolean);
+            for (const item of meta) {
+                const span = document.createElement('span');
+                span.className = 'model-lang';
+                span.textContent = item;
+                metaDiv.appendChild(span);
+            }
+            infoDiv.appendChild(textDiv);
+            infoDiv.appendChild(metaDiv);
+
+            const actions = document.createElement('div');
+            actions.className = 'history-actions';
+
+            const copyBtn = document.createElement('button');
+            copyBtn.className = 'btn-secondary';
+            copyBtn.textContent = 'Copy';
+            copyBtn.addEventListener('click', async () => {
+                try {
+                    await api.copyHistoryEntry(entry.id);
+                } catch (err) {
+                    console.error('Failed to copy history entry:', err);
+                }
+            });
+
+            const reinjectBtn = document.createElement('button');
+            reinjectBtn.className = 'btn-secondary';
+            reinjectBtn.textContent = 'Insert';
+            reinjectBtn.title = 'Insert into the previously focused app';
+            reinjectBtn.addEventListener('click', async () => {
+                try {
+                    await api.reinjectHistoryEntry(entry.id);
+                } catch (err) {
+                    console.error('Failed to re-inject history entry:', err);
+                }
+            });
+
+            const deleteBtn = document.createElement('button');
+            deleteBtn.className = 'btn-delete';
+            deleteBtn.title = 'Delete';
+            deleteBtn.textContent = '\u2715';
+            deleteBtn.addEventListener('click', async () => {
+                try {
+                    await api.deleteHistoryEntry(entry.id);
+                    await loadHistory();
+                } catch (err) {
+                    console.error('Failed to delete history entry:', err);
+                }
+            });
+
+            actions.appendChild(copyBtn);
+            actions.appendChild(reinjectBtn);
+            actions.appendChild(deleteBtn);
+            card.appendChild(infoDiv);
+            card.appendChild(actions);
+            container.appendChild(card);
+        }
+    } catch (e) {
+        console.error('Failed to load history:', e);
+    }
+}
+
+let historySearchTimer = null;
+document.getElementById('history-search').addEventListener('input', () => {
+    clearTimeout(historySearchTimer);
+    historySearchTimer = setTimeout(loadHistory, 200);
+});
+
+document.getElementById('clear-history').addEventListener('click', async () => {
+    const confirmed = await showConfirm('Delete all transcription history?');
+    if (!confirmed) return;
+    try {
+        await api.clearHistory();
+        await loadHistory();
+    } catch (err) {
+        console.error('Failed to clear history:', err);
+    }
+});
+
+api.onHistoryUpdated(() => loadHistory());
+
 // Native confirm/alert is blocked in Tauri webview
 function showConfirm(message) {
     return new Promise((resolve) => {
@@ -374,6 +644,61 @@ function formatTime(seconds) {
     return `${seconds}s`;
 }
 
+async function loadInputDevices() {
+    try {
+        const [devices, settings] = await Promise.all([api.listInputDevices(), api.getSettings()]);
+        const chain = settings.stt.preferred_input_devices || [];
+        ['input-device', 'input-device-fallback'].forEach((id, i) => {
+            const select = document.getElementById(id);
+            select.innerHTML = '<option value="">System default</option>';
+            const names = devices.map(d => d.name);
+            // Keep a saved device selectable while it is unplugged
+            if (chain[i] && !names.includes(chain[i])) names.push(chain[i]);
+            names.forEach(name => {
+                const option = document.createElement('option');
+                option.value = name;
+                const device = devices.find(d => d.name === name);
+                option.textContent = device ? name : `${name} (not connected)`;
+                select.appendChild(option);
+            });
+            select.value = chain[i] || '';
+        });
+    } catch (e) {
+        console.error('Failed to load input devices:', e);
+    }
+}
+
+async function saveInputDevices() {
+    const devices = ['input-device', 'input-device-fallback']
+        .map(id => document.getElementById(id).value)
+        .filter(name => name);
+    try {
+        await api.setPreferredInputDevices(devices);
+    } catch (e) {
+        console.error('Failed to save input devices:', e);
+    }
+}
+
+['input-device', 'input-device-fallback'].forEach(id => {
+    document.getElementById(id).addEventListener('change', saveInputDevices);
+});
+
+api.onDeviceChanged(() => loadInputDevices());
+
+function showWarmMicStatus(active) {
+    document.getElementById('warm-mic-indicator').style.display = active ? 'inline-block' : 'none';
+}
+
+api.onWarmMicStatus((data) => showWarmMicStatus(data.active));
+
+async function loadWarmMicStatus() {
+    try {
+        showWarmMicStatus(await api.getWarmMicStatus());
+    } catch (e) {
+        console.error('Failed to load warm mic status:', e);
+    }
+}
+
 async function loadVersion() {
     try {
         const version = await api.getAppVersion();
@@ -389,6 +714,10 @@ document.addEventListener('DOMContentLoaded', () => {
     checkMicrophone();
     loadCatalog();
     loadInstalled();
+    loadHotwords();
+    loadHistory();
+    loadWarmMicStatus();
+    loadInputDevices();
     loadVersion();
 });
 
@@ -401,6 +730,15 @@ api.onSttShortcutChanged((data) => {
     }
 });
 
+api.onTtsShortcutChanged((data) => {
+    if (!data || !data.shortcut) return;
+    const el = document.getElementById('tts-shortcut');
+    if (el) {
+        el.value = data.shortcut;
+        el.dataset.previousValue = data.shortcut;
+    }
+});
+
 api.onPermissionMissing((data) => {
     const banner = document.getElementById('permission-banner');
     const text = document.getElementById('permission-banner-text');
@@ -6,10 +6,14 @@ const modeTranscribing = document.getElementById('mode-transcribing');
 const modeTts = document.getElementById('mode-tts');
 const modeLoading = document.getElementById('mode-loading');
 const sttStatus = document.getElementById('stt-status');
+const ttsStatus = document.getElementById('tts-status');
 
 const shortcutHint = document.getElementById('shortcut-hint');
 api.getSttShortcutLabel().then(label => { shortcutHint.textContent = label; });
 api.onSttShortcutChanged((data) => { shortcutHint.textContent = data.label; });
+const ttsShortcutHint = document.getElementById('tts-shortcut-hint');
+api.getTtsShortcutLabel().then(label => { ttsShortcutHint.textContent = label; });
+api.onTtsShortcutChanged((data) => { ttsShortcutHint.textContent = data.label; });
 
 const BAR_COUNT = 48;
 const visualizer = document.getElementById('audio-visualizer');
@@ -48,6 +52,11 @@ function showMode(mode) {
             sttStatus.textContent = 'Listening...';
             overlay.classList.add('visible');
             break;
+        case 'armed':
+            modeStt.classList.remove('hidden');
+            sttStatus.textContent = 'Waiting for speech...';
+            overlay.classList.add('visible');
+            break;
         case 'transcribing':
             resetBars();
             document.getElementById('transcription-progress').style.removeProperty('width');
@@ -58,6 +67,17 @@ function showMode(mode) {
             modeTts.classList.remove('hidden');
             overlay.classList.add('visible');
             break;
+        case 'synthesizing':
+            document.getElementById('tts-progress').style.removeProperty('width');
+            modeTts.classList.remove('hidden');
+            ttsStatus.textContent = 'Preparing voice...';
+            overlay.classList.add('visible');
+            break;
+        case 'playing':
+            modeTts.classList.remove('hidden');
+            ttsStatus.textContent = 'Reading...';
+            overlay.classList.add('visible');
+            break;
         case 'idle':
             resetBars();
             overlay.classList.remove('visible');
@@ -94,6 +114,25 @@ api.onStreamingTranscription((data) => {
     }
 });
 
+api.onAutoStopCountdown((data) => {
+    if (previousMode !== 'recording') return;
+    if (data.remaining_ms === null) {
+        sttStatus.textContent = 'Listening...';
+        return;
+    }
+    const seconds = Math.ceil(data.remaining_ms / 1000);
+    sttStatus.textContent = data.reason === 'no_speech'
+        ? `No speech, cancelling in ${seconds}s`
+        : `Stopping in ${seconds}s`;
+});
+
+api.onDeviceChanged((data) => {
+    if (previousMode !== 'recording' && previousMode !== 'armed') return;
+    sttStatus.textContent = data.device
+        ? `Switched to ${data.device}`
+        : 'Microphone disconnected';
+});
+
 api.onTranscriptionProgress((data) => {
     const fill = document.getElementById('transcription-progress');
     if (fill && data.total > 1) {
@@ -102,7 +141,10 @@ api.onTranscriptionProgress((data) => {
 });
 
 api.onTranscriptionComplete(() => {
-    setTimeout(() => showMode('idle'), 500);
+    // Voice-activated mode goes back to listening instead of closing
+    setTimeout(() => {
+        if (previousMode === 'idle' || previousMode === 'transcribing') showMode('idle');
+    }, 500);
 });
 
 api.onPlaybackProgress((data) => {